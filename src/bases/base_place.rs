@@ -0,0 +1,12 @@
+use crate::derives::PlaceDerive;
+
+#[derive(Debug, Clone)]
+pub struct BasePlace {
+  pub id: i64,
+}
+
+impl PlaceDerive for BasePlace {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
@@ -0,0 +1,12 @@
+use crate::derives::PrivateServerDerive;
+
+#[derive(Debug, Clone)]
+pub struct BasePrivateServer {
+  pub id: i64,
+}
+
+impl PrivateServerDerive for BasePrivateServer {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
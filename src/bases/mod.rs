@@ -27,12 +27,16 @@
 
 mod base_asset;
 mod base_group;
+mod base_place;
 mod base_plugin;
+mod base_private_server;
 mod base_universe;
 mod base_user;
 
 pub use base_asset::BaseAsset;
 pub use base_group::BaseGroup;
+pub use base_place::BasePlace;
 pub use base_plugin::BasePlugin;
+pub use base_private_server::BasePrivateServer;
 pub use base_universe::BaseUniverse;
 pub use base_user::BaseUser;
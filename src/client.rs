@@ -1,18 +1,22 @@
 use crate::{
-  bases::{BaseAsset, BaseGroup, BasePlugin, BaseUniverse, BaseUser},
-  models::{Badge, EconomyAsset, Group, Place, Plugin, Presence, PreviousUsernamesSkinnyUser, SkinnyUser, Universe, User},
+  bases::{BaseAsset, BaseGroup, BasePlace, BasePlugin, BasePrivateServer, BaseUniverse, BaseUser},
+  models::{
+    Badge, EconomyAsset, Group, Place, Plugin, Presence, PreviousUsernamesSkinnyUser, SkinnyGroup, SkinnyGroupWithMemberCount, SkinnyUser, Universe,
+    UniverseVotes, User, UserPrivateServer,
+  },
+  oauth::{OAuthClient, TokenRefresher, TokenStore},
   util::{
     api_helper,
     paging::{identity_mapper, PageIterator},
     responses::{ApiArrayResponse, UserPresencesResponse},
-    ResultExtensions, RobloxResult,
+    Credential, ResultExtensions, RobloxResult,
   },
 };
 use serde_json::json;
 
 // Transforms a list of ids into a comma-separated string "1,2,3,4,5" for use in some multi-get endpoints
 fn ids_to_string(ids: Vec<i64>) -> String {
-  let mut s = ids.iter().map(|id| format!("{},", id.to_string())).collect::<String>();
+  let mut s = ids.iter().map(|id| format!("{},", id)).collect::<String>();
   s.pop();
   s
 }
@@ -21,6 +25,22 @@ pub fn set_roblosecurity(roblosecurity: &str) {
   api_helper::set_roblosecurity(roblosecurity);
 }
 
+/// Sets the credential used by Open Cloud requests that aren't given one explicitly
+pub fn set_api_key(api_key: &str) {
+  api_helper::set_credential(Credential::ApiKey(api_key.to_owned()));
+}
+
+/// Sets an OAuth 2.0 access token as the credential used by Open Cloud requests that aren't given one explicitly
+pub fn set_access_token(access_token: &str) {
+  api_helper::set_credential(Credential::Bearer(access_token.to_owned()));
+}
+
+/// Sets the token store consulted by Open Cloud requests that aren't given a credential explicitly.
+/// Its tokens are refreshed with `client` before they expire, or when Roblox rejects them
+pub fn set_token_store(client: OAuthClient, store: impl TokenStore + 'static) {
+  api_helper::set_token_refresher(TokenRefresher::new(client, Box::new(store)));
+}
+
 pub fn search_users(keyword: &str) -> PageIterator<PreviousUsernamesSkinnyUser, PreviousUsernamesSkinnyUser> {
   PageIterator::new(
     format!("https://users.roblox.com/v1/users/search?keyword={}", keyword),
@@ -29,6 +49,18 @@ pub fn search_users(keyword: &str) -> PageIterator<PreviousUsernamesSkinnyUser,
   )
 }
 
+/// Searches groups by name. With `prioritize_exact`, a group named exactly `keyword` comes first
+pub fn search_groups(keyword: &str, prioritize_exact: bool) -> PageIterator<SkinnyGroupWithMemberCount, SkinnyGroupWithMemberCount> {
+  PageIterator::new(
+    format!(
+      "https://groups.roblox.com/v1/groups/search?keyword={}&prioritizeExactMatch={}",
+      keyword, prioritize_exact
+    ),
+    identity_mapper,
+    None,
+  )
+}
+
 pub fn base_plugin(plugin_id: i64) -> BasePlugin {
   BasePlugin { id: plugin_id }
 }
@@ -41,6 +73,14 @@ pub fn base_user(user_id: i64) -> BaseUser {
   BaseUser { id: user_id }
 }
 
+pub fn base_place(place_id: i64) -> BasePlace {
+  BasePlace { id: place_id }
+}
+
+pub fn base_private_server(private_server_id: i64) -> BasePrivateServer {
+  BasePrivateServer { id: private_server_id }
+}
+
 pub fn base_universe(universe_id: i64) -> BaseUniverse {
   BaseUniverse { id: universe_id }
 }
@@ -54,6 +94,15 @@ pub async fn authenticated_user(cookie: Option<String>) -> RobloxResult<SkinnyUs
   // might need a map_err
 }
 
+/// Lists the private servers the authenticated user owns or has access to
+pub fn authenticated_private_servers(cookie: Option<String>) -> PageIterator<UserPrivateServer, UserPrivateServer> {
+  PageIterator::new(
+    "https://games.roblox.com/v1/private-servers/my-private-servers?privateServersTab=MyPrivateServers".to_owned(),
+    identity_mapper,
+    cookie,
+  )
+}
+
 pub async fn users_from_ids(user_ids: Vec<i64>, exclude_banned_users: bool) -> RobloxResult<Vec<SkinnyUser>> {
   api_helper::post(
     "https://users.roblox.com/v1/users".to_owned(),
@@ -109,6 +158,17 @@ pub async fn universes_from_ids(universe_ids: Vec<i64>) -> RobloxResult<Vec<Univ
   .map(|data| data.data)
 }
 
+pub async fn universe_votes_from_ids(universe_ids: Vec<i64>) -> RobloxResult<Vec<UniverseVotes>> {
+  api_helper::get(
+    format!("https://games.roblox.com/v1/games/votes?universeIds={}", ids_to_string(universe_ids)),
+    None,
+  )
+  .await
+  .map_async(api_helper::deserialize_body::<ApiArrayResponse<UniverseVotes>>)
+  .await?
+  .map(|data| data.data)
+}
+
 pub async fn places_from_ids(place_ids: Vec<i64>) -> RobloxResult<Vec<Place>> {
   api_helper::get(
     format!(
@@ -122,6 +182,14 @@ pub async fn places_from_ids(place_ids: Vec<i64>) -> RobloxResult<Vec<Place>> {
   .await?
 }
 
+pub async fn groups_from_ids(group_ids: Vec<i64>) -> RobloxResult<Vec<SkinnyGroup>> {
+  api_helper::get(format!("https://groups.roblox.com/v2/groups?groupIds={}", ids_to_string(group_ids)), None)
+    .await
+    .map_async(api_helper::deserialize_body::<ApiArrayResponse<SkinnyGroup>>)
+    .await?
+    .map(|data| data.data)
+}
+
 pub async fn plugins_from_ids(plugin_ids: Vec<i64>) -> RobloxResult<Vec<Plugin>> {
   api_helper::get(
     format!("https://develop.roblox.com/v1/plugins?pluginIds={}", ids_to_string(plugin_ids)),
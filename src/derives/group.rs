@@ -1,14 +1,86 @@
 use crate::{
-  models::{GroupRole, GroupSettings, GroupShout, JoinRequest, Member, SocialLink, WallPost},
+  authenticated_user, base_user,
+  derives::{UniverseDerive, UserDerive},
+  models::{
+    validate_payout, validate_recurring_payouts, AuditLogActionType, AuditLogEntry, GameAccessFilter, GamePass, GroupPermission,
+    GroupRelationshipType, GroupRole, GroupSettings, GroupShout, JoinRequest, JoinRequestReport, JoinRequestRules, JoinStatus, Member,
+    PayoutRecipient, RevenuePeriod, RevenueSummary, RoleChange, RolePermissions, RoleSelector, SkinnyAsset, SkinnyGroup, SkinnyUniverse, SkinnyUser,
+    SocialLink, Transaction, TransactionKind, WallPost, WallPostDeletionFailure, WallPostFilter, WallPurgeReport,
+  },
+  open_cloud::AssetFile,
+  user_from_id,
   util::{
     api_helper,
-    paging::{identity_mapper, PageIterator},
-    responses::{ApiArrayResponse, GroupRolesResponse},
-    ResultExtensions, RobloxResult,
+    multipart::MultipartForm,
+    paging::{collect_stream, identity_mapper, PageIterator, RowPageIterator},
+    responses::{
+      ApiArrayResponse, AuditLogEntryResponse, CreatorGameResponse, CurrencyResponse, GroupDescriptionResponse, GroupNameHistoryResponse,
+      GroupNameResponse, GroupRolesResponse,
+    },
+    OxidError, ResultExtensions, RobloxResult,
   },
 };
 use async_trait::async_trait;
-use serde_json::json;
+use serde_json::{json, Map, Value};
+
+// Some errors of group actions are only distinguishable by their message
+fn group_action_error(error: OxidError) -> OxidError {
+  match error {
+    OxidError::Api(errors) => {
+      let message = errors.iter().map(|error| error.message.to_lowercase()).collect::<Vec<_>>().join(" ");
+
+      if message.contains("captcha") {
+        OxidError::CaptchaRequired
+      } else if message.contains("maximum number of groups") {
+        OxidError::TooManyGroups
+      } else if message.contains("already requested") {
+        OxidError::JoinRequestPending
+      } else {
+        OxidError::Api(errors)
+      }
+    }
+    error => error,
+  }
+}
+
+fn payout_body(payout_type: &str, recipients: &[(i64, i64)]) -> Value {
+  let recipients = recipients
+    .iter()
+    .map(|(user_id, amount)| {
+      json!({
+          "recipientId": user_id,
+          "recipientType": "User",
+          "amount": amount
+      })
+    })
+    .collect::<Vec<_>>();
+
+  json!({
+      "PayoutType": payout_type,
+      "Recipients": recipients
+  })
+}
+
+/// Moves a member to the role planned by `plan` out of the group's roles, their current role id and the rank of the
+/// authenticated user
+async fn change_rank<G: Group + Sync + ?Sized>(
+  group: &G,
+  user_id: i64,
+  cookie: Option<String>,
+  plan: impl FnOnce(&[GroupRole], i64, i64) -> RobloxResult<RoleChange> + Send,
+) -> RobloxResult<RoleChange> {
+  let roles = group.roles(cookie.clone()).await?;
+
+  let authenticated_id = authenticated_user(cookie.clone()).await?.id;
+  let own_rank = base_user(authenticated_id).role_in_group(group.id()).await?.role.rank;
+
+  let current_id = base_user(user_id).role_in_group(group.id()).await?.role.id;
+  let change = plan(&roles, current_id, own_rank)?;
+
+  group.set_user_role(user_id, change.new_role.id, cookie).await?;
+
+  Ok(change)
+}
 
 #[async_trait]
 pub trait Group {
@@ -26,9 +98,63 @@ pub trait Group {
     .await?
   }
 
+  /// Fetches the current shout of the group
+  async fn shout(&self, cookie: Option<String>) -> RobloxResult<Option<GroupShout>> {
+    api_helper::get(format!("https://groups.roblox.com/v1/groups/{}", self.id()), cookie)
+      .await
+      .map_async(api_helper::deserialize_body::<crate::models::Group>)
+      .await?
+      .map(|data| data.shout)
+  }
+
+  fn name_history(&self, cookie: Option<String>) -> PageIterator<GroupNameHistoryResponse, String> {
+    PageIterator::new(
+      format!("https://groups.roblox.com/v1/groups/{}/name-history", self.id()),
+      |data| data.name.clone(),
+      cookie,
+    )
+  }
+
+  /// Returns the new description, which may have been filtered
+  async fn update_description(&self, description: &str, cookie: Option<String>) -> RobloxResult<String> {
+    api_helper::patch(
+      format!("https://groups.roblox.com/v1/groups/{}/description", self.id()),
+      json!({ "description": description }),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body::<GroupDescriptionResponse>)
+    .await?
+    .map(|data| data.new_description)
+  }
+
+  /// Renames the group, which costs Robux from the authenticated user
+  async fn update_name(&self, name: &str, cookie: Option<String>) -> RobloxResult<String> {
+    api_helper::patch(
+      format!("https://groups.roblox.com/v1/groups/{}/name", self.id()),
+      json!({ "name": name }),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body::<GroupNameResponse>)
+    .await?
+    .map(|data| data.new_name)
+  }
+
+  /// Uploads a new icon. The image goes through moderation before it is shown
+  async fn update_icon(&self, icon: AssetFile, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::patch_multipart(
+      format!("https://groups.roblox.com/v1/groups/icon?groupId={}", self.id()),
+      MultipartForm::new().file("Files", &icon.name, &icon.content_type, &icon.content),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
   async fn accept_join_request(&self, user_id: i64, cookie: Option<String>) -> RobloxResult<()> {
     api_helper::post(
-      format!("https://groups.roblox.com/v1/groups/{}/join-requests/users/#{}", self.id(), user_id),
+      format!("https://groups.roblox.com/v1/groups/{}/join-requests/users/{}", self.id(), user_id),
       json!({}),
       cookie,
     )
@@ -45,12 +171,136 @@ pub trait Group {
     .map(|_| ())
   }
 
+  async fn accept_join_requests(&self, user_ids: Vec<i64>, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!("https://groups.roblox.com/v1/groups/{}/join-requests", self.id()),
+      json!({ "UserIds": user_ids }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  async fn decline_join_requests(&self, user_ids: Vec<i64>, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::delete_with_body(
+      format!("https://groups.roblox.com/v1/groups/{}/join-requests", self.id()),
+      json!({ "UserIds": user_ids }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  /// Checks every pending join request against the rules, then accepts the requesters that pass them
+  ///
+  /// note: looks up each requester, and their groups if [JoinRequestRules::require_allied_group] is set
+  async fn process_join_requests(&self, rules: JoinRequestRules, cookie: Option<String>) -> RobloxResult<JoinRequestReport> {
+    let requests = collect_stream(self.join_requests(cookie.clone()).into_stream()).await?;
+
+    let allies = if rules.require_allied_group {
+      collect_stream(self.allies(cookie.clone()).into_stream()).await?
+    } else {
+      Vec::new()
+    };
+
+    let mut report = JoinRequestReport::default();
+    for request in requests {
+      let user = user_from_id(request.requester.id).await?;
+
+      // Checking the allied groups takes another request, so only requesters passing every other rule are checked
+      let in_allied_group = match rules.evaluate(&user, true) {
+        None if rules.require_allied_group => user
+          .group_roles()
+          .await?
+          .iter()
+          .any(|role| allies.iter().any(|ally| ally.id == role.group.id)),
+        _ => true,
+      };
+
+      match rules.evaluate(&user, in_allied_group) {
+        Some(rejection) => report.rejected.push((request.requester, rejection)),
+        None => report.accepted.push(request.requester),
+      }
+    }
+
+    if !report.accepted.is_empty() {
+      self
+        .accept_join_requests(report.accepted.iter().map(|user| user.id).collect(), cookie.clone())
+        .await?;
+    }
+    if rules.decline_failing && !report.rejected.is_empty() {
+      self
+        .decline_join_requests(report.rejected.iter().map(|(user, _)| user.id).collect(), cookie)
+        .await?;
+    }
+
+    Ok(report)
+  }
+
   async fn kick(&self, user_id: i64, cookie: Option<String>) -> RobloxResult<()> {
     api_helper::delete(format!("https://groups.roblox.com/v1/groups/{}/users/{}", self.id(), user_id), cookie)
       .await
       .map(|_| ())
   }
 
+  /// Joins the group as the authenticated user. Groups that require approval get a join request instead
+  ///
+  /// note: fails with [OxidError::TooManyGroups], [OxidError::JoinRequestPending] or [OxidError::CaptchaRequired]
+  async fn join(&self, cookie: Option<String>) -> RobloxResult<JoinStatus> {
+    api_helper::post(
+      format!("https://groups.roblox.com/v1/groups/{}/users", self.id()),
+      json!({}),
+      cookie.clone(),
+    )
+    .await
+    .map_err(group_action_error)?;
+
+    // The endpoint answers with an empty body either way, so check whether the user became a member
+    let user_id = authenticated_user(cookie).await?.id;
+    let joined = base_user(user_id).group_roles().await?.iter().any(|role| role.group.id == self.id());
+
+    Ok(if joined { JoinStatus::Joined } else { JoinStatus::Pending })
+  }
+
+  /// Leaves the group as the authenticated user
+  async fn leave(&self, cookie: Option<String>) -> RobloxResult<()> {
+    let user_id = authenticated_user(cookie.clone()).await?.id;
+    self.kick(user_id, cookie).await
+  }
+
+  /// Shows the group on the authenticated user's profile
+  async fn set_as_primary(&self, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      "https://groups.roblox.com/v1/user/groups/primary".to_owned(),
+      json!({ "groupId": self.id() }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  /// Claims ownership of a group without an owner, as the authenticated user
+  async fn claim_ownership(&self, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!("https://groups.roblox.com/v1/groups/{}/claim-ownership", self.id()),
+      json!({}),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  /// Transfers ownership of the group to another member
+  async fn change_owner(&self, user_id: i64, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!("https://groups.roblox.com/v1/groups/{}/change-owner", self.id()),
+      json!({ "userId": user_id }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
   async fn roles(&self, cookie: Option<String>) -> RobloxResult<Vec<GroupRole>> {
     api_helper::get(format!("https://groups.roblox.com/v1/groups/{}/roles", self.id()), cookie)
       .await
@@ -69,6 +319,116 @@ pub trait Group {
     .map(|_| ())
   }
 
+  /// Ranks a member without having to look up the id of the role first
+  ///
+  /// note: fails if the role or the member's current role is at or above the authenticated user's rank
+  async fn set_rank(&self, user_id: i64, role: RoleSelector, cookie: Option<String>) -> RobloxResult<RoleChange> {
+    change_rank(self, user_id, cookie, |roles, current_id, own_rank| {
+      RoleChange::to_role(roles, current_id, own_rank, &role)
+    })
+    .await
+  }
+
+  /// Moves a member to the next role up
+  async fn promote(&self, user_id: i64, cookie: Option<String>) -> RobloxResult<RoleChange> {
+    change_rank(self, user_id, cookie, RoleChange::promotion).await
+  }
+
+  /// Moves a member to the next role down. Members can't be demoted to the guest role
+  async fn demote(&self, user_id: i64, cookie: Option<String>) -> RobloxResult<RoleChange> {
+    change_rank(self, user_id, cookie, RoleChange::demotion).await
+  }
+
+  /// Creates a role, paying its Robux cost from the group funds if `using_group_funds` is true or from the
+  /// authenticated user otherwise
+  async fn create_role(&self, name: &str, description: &str, rank: u8, using_group_funds: bool, cookie: Option<String>) -> RobloxResult<GroupRole> {
+    api_helper::post(
+      format!("https://groups.roblox.com/v1/groups/{}/rolesets/create", self.id()),
+      json!({
+          "name": name,
+          "description": description,
+          "rank": rank,
+          "usingGroupFunds": using_group_funds
+      }),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  /// Renames and/or re-ranks a role. Fields that are `None` are left unchanged
+  ///
+  /// note: the endpoint requires every field, so the current role is fetched through [GroupDerive::roles] first
+  async fn update_role(
+    &self,
+    role_id: i64,
+    name: Option<&str>,
+    description: Option<&str>,
+    rank: Option<u8>,
+    cookie: Option<String>,
+  ) -> RobloxResult<GroupRole> {
+    let role = self
+      .roles(cookie.clone())
+      .await?
+      .into_iter()
+      .find(|role| role.id == role_id)
+      .ok_or(OxidError::Validation("The role was not found in this group.".to_owned()))?;
+
+    api_helper::patch(
+      format!("https://groups.roblox.com/v1/groups/{}/rolesets/{}", self.id(), role_id),
+      json!({
+          "name": name.unwrap_or(&role.name),
+          "description": description.or(role.description.as_deref()).unwrap_or_default(),
+          "rank": rank.unwrap_or(role.rank)
+      }),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  async fn delete_role(&self, role_id: i64, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::delete(format!("https://groups.roblox.com/v1/groups/{}/rolesets/{}", self.id(), role_id), cookie)
+      .await
+      .map(|_| ())
+  }
+
+  async fn role_permissions(&self, role_id: i64, cookie: Option<String>) -> RobloxResult<RolePermissions> {
+    api_helper::get(
+      format!("https://groups.roblox.com/v1/groups/{}/roles/{}/permissions", self.id(), role_id),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  /// Grants or revokes permissions of a role. Permissions that aren't listed are left unchanged
+  async fn update_role_permissions(&self, role_id: i64, permissions: &[(GroupPermission, bool)], cookie: Option<String>) -> RobloxResult<()> {
+    let permissions = permissions
+      .iter()
+      .map(|(permission, granted)| (permission.serialize(), Value::Bool(*granted)))
+      .collect::<Map<String, Value>>();
+
+    api_helper::patch(
+      format!("https://groups.roblox.com/v1/groups/{}/roles/{}/permissions", self.id(), role_id),
+      json!({ "permissions": permissions }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  fn members_with_role(&self, role_id: i64, cookie: Option<String>) -> PageIterator<SkinnyUser, SkinnyUser> {
+    PageIterator::new(
+      format!("https://groups.roblox.com/v1/groups/{}/roles/{}/users", self.id(), role_id),
+      identity_mapper,
+      cookie,
+    )
+  }
+
   fn members(&self, cookie: Option<String>) -> PageIterator<Member, Member> {
     PageIterator::new(
       format!("https://groups.roblox.com/v1/groups/{}/users", self.id()),
@@ -124,6 +484,41 @@ pub trait Group {
     .map(|_| ())
   }
 
+  /// note: fails with [OxidError::CaptchaRequired] when Roblox asks for a captcha, which happens for most accounts
+  async fn post_to_wall(&self, body: &str, cookie: Option<String>) -> RobloxResult<WallPost> {
+    api_helper::post(
+      format!("https://groups.roblox.com/v2/groups/{}/wall/posts", self.id()),
+      json!({ "body": body }),
+      cookie,
+    )
+    .await
+    .map_err(group_action_error)
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  /// Goes through every wall post and deletes the ones matching the filter. With `dry_run`, the matching posts
+  /// are only reported
+  async fn purge_wall_posts(&self, filter: WallPostFilter, dry_run: bool, cookie: Option<String>) -> RobloxResult<WallPurgeReport> {
+    let mut report = WallPurgeReport {
+      dry_run,
+      ..Default::default()
+    };
+
+    // Collect the wall before deleting anything so the deletions don't shift the pages being read
+    let posts = collect_stream(self.wall_posts(cookie.clone()).into_stream()).await?;
+    for post in posts.into_iter().filter(|post| filter.matches(post)) {
+      if !dry_run {
+        if let Err(error) = self.delete_wall_post(post.id, cookie.clone()).await {
+          report.failed.push(WallPostDeletionFailure { post: post.clone(), error });
+        }
+      }
+      report.matched.push(post);
+    }
+
+    Ok(report)
+  }
+
   fn wall_posts(&self, cookie: Option<String>) -> PageIterator<WallPost, WallPost> {
     PageIterator::new(
       format!("https://groups.roblox.com/v2/groups/{}/wall/posts", self.id()),
@@ -157,4 +552,241 @@ pub trait Group {
     .map_async(api_helper::deserialize_body)
     .await?
   }
+
+  /// Lists the audit log, optionally only the actions of a type and/or performed by a user
+  fn audit_log(
+    &self,
+    action_type: Option<AuditLogActionType>,
+    user_id: Option<i64>,
+    cookie: Option<String>,
+  ) -> PageIterator<AuditLogEntryResponse, AuditLogEntry> {
+    let mut filters = Vec::new();
+    if let Some(action_type) = action_type {
+      filters.push(format!("actionType={}", action_type.serialize()));
+    }
+    if let Some(user_id) = user_id {
+      filters.push(format!("userId={}", user_id));
+    }
+
+    let mut url = format!("https://groups.roblox.com/v1/groups/{}/audit-log", self.id());
+    if !filters.is_empty() {
+      url = format!("{}?{}", url, filters.join("&"));
+    }
+
+    PageIterator::new(url, AuditLogEntryResponse::to_entry, cookie)
+  }
+
+  /// The amount of Robux in the group funds
+  async fn funds(&self, cookie: Option<String>) -> RobloxResult<i64> {
+    api_helper::get(format!("https://economy.roblox.com/v1/groups/{}/currency", self.id()), cookie)
+      .await
+      .map_async(api_helper::deserialize_body::<CurrencyResponse>)
+      .await?
+      .map(|data| data.robux)
+  }
+
+  async fn revenue_summary(&self, period: RevenuePeriod, cookie: Option<String>) -> RobloxResult<RevenueSummary> {
+    api_helper::get(
+      format!(
+        "https://economy.roblox.com/v1/groups/{}/revenue/summary/{}",
+        self.id(),
+        period.serialize()
+      ),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  fn transactions(&self, kind: TransactionKind, cookie: Option<String>) -> PageIterator<Transaction, Transaction> {
+    PageIterator::new(
+      format!(
+        "https://economy.roblox.com/v2/groups/{}/transactions?transactionType={}",
+        self.id(),
+        kind.serialize()
+      ),
+      identity_mapper,
+      cookie,
+    )
+  }
+
+  /// The users receiving a recurring percentage of the group's revenue
+  async fn payout_recipients(&self, cookie: Option<String>) -> RobloxResult<Vec<PayoutRecipient>> {
+    api_helper::get(format!("https://groups.roblox.com/v1/groups/{}/payouts", self.id()), cookie)
+      .await
+      .map_async(api_helper::deserialize_body::<ApiArrayResponse<PayoutRecipient>>)
+      .await?
+      .map(|data| data.data)
+  }
+
+  /// Pays each user an amount of Robux from the group funds once. Takes pairs of user ids and amounts
+  async fn payout(&self, recipients: &[(i64, i64)], cookie: Option<String>) -> RobloxResult<()> {
+    validate_payout(recipients)?;
+
+    api_helper::post(
+      format!("https://groups.roblox.com/v1/groups/{}/payouts", self.id()),
+      payout_body("FixedAmount", recipients),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  /// Replaces the recurring payouts of the group. Takes pairs of user ids and percentages, which can add up to at most 100
+  async fn set_recurring_payouts(&self, recipients: &[(i64, i32)], cookie: Option<String>) -> RobloxResult<()> {
+    validate_recurring_payouts(recipients)?;
+
+    let recipients = recipients
+      .iter()
+      .map(|(user_id, percentage)| (*user_id, i64::from(*percentage)))
+      .collect::<Vec<_>>();
+
+    api_helper::post(
+      format!("https://groups.roblox.com/v1/groups/{}/payouts/recurring", self.id()),
+      payout_body("Percentage", &recipients),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  fn allies(&self, cookie: Option<String>) -> RowPageIterator<SkinnyGroup, SkinnyGroup> {
+    self.relationships(GroupRelationshipType::Allies, cookie)
+  }
+
+  fn enemies(&self, cookie: Option<String>) -> RowPageIterator<SkinnyGroup, SkinnyGroup> {
+    self.relationships(GroupRelationshipType::Enemies, cookie)
+  }
+
+  fn relationships(&self, relationship_type: GroupRelationshipType, cookie: Option<String>) -> RowPageIterator<SkinnyGroup, SkinnyGroup> {
+    RowPageIterator::new(
+      format!(
+        "https://groups.roblox.com/v1/groups/{}/relationships/{}",
+        self.id(),
+        relationship_type.serialize()
+      ),
+      "relatedGroups",
+      identity_mapper,
+      cookie,
+    )
+  }
+
+  /// Lists the groups that requested a relationship with this group
+  fn relationship_requests(&self, relationship_type: GroupRelationshipType, cookie: Option<String>) -> RowPageIterator<SkinnyGroup, SkinnyGroup> {
+    RowPageIterator::new(
+      format!(
+        "https://groups.roblox.com/v1/groups/{}/relationships/{}/requests",
+        self.id(),
+        relationship_type.serialize()
+      ),
+      "relatedGroups",
+      identity_mapper,
+      cookie,
+    )
+  }
+
+  /// Sends an ally request, or declares the other group an enemy
+  async fn request_relationship(&self, relationship_type: GroupRelationshipType, group_id: i64, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!(
+        "https://groups.roblox.com/v1/groups/{}/relationships/{}/{}",
+        self.id(),
+        relationship_type.serialize(),
+        group_id
+      ),
+      json!({}),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  async fn accept_relationship_request(&self, relationship_type: GroupRelationshipType, group_id: i64, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!(
+        "https://groups.roblox.com/v1/groups/{}/relationships/{}/requests/{}",
+        self.id(),
+        relationship_type.serialize(),
+        group_id
+      ),
+      json!({}),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  async fn decline_relationship_request(&self, relationship_type: GroupRelationshipType, group_id: i64, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::delete(
+      format!(
+        "https://groups.roblox.com/v1/groups/{}/relationships/{}/requests/{}",
+        self.id(),
+        relationship_type.serialize(),
+        group_id
+      ),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  async fn remove_relationship(&self, relationship_type: GroupRelationshipType, group_id: i64, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::delete(
+      format!(
+        "https://groups.roblox.com/v1/groups/{}/relationships/{}/{}",
+        self.id(),
+        relationship_type.serialize(),
+        group_id
+      ),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  fn games(&self, access_filter: GameAccessFilter, cookie: Option<String>) -> PageIterator<CreatorGameResponse, SkinnyUniverse> {
+    PageIterator::new(
+      format!(
+        "https://games.roblox.com/v2/groups/{}/games?accessFilter={}",
+        self.id(),
+        access_filter.serialize()
+      ),
+      |data| SkinnyUniverse {
+        id: data.id,
+        name: data.name.clone(),
+        root_place_id: data.root_place.id,
+      },
+      cookie,
+    )
+  }
+
+  /// Lists the assets of a type created by the group, such as `Decal` or `Shirt`
+  ///
+  /// note: requires a cookie with permission to manage the group's items
+  fn group_assets(&self, asset_type: &str, cookie: Option<String>) -> PageIterator<SkinnyAsset, SkinnyAsset> {
+    PageIterator::new(
+      format!(
+        "https://itemconfiguration.roblox.com/v1/creations/get-assets?assetType={}&groupId={}&isArchived=false",
+        asset_type,
+        self.id()
+      ),
+      identity_mapper,
+      cookie,
+    )
+  }
+
+  /// Collects the gamepasses of every game of the group
+  ///
+  /// note: uses [GroupDerive::games] and [UniverseDerive::gamepasses] under the hood
+  async fn group_gamepasses(&self, cookie: Option<String>) -> RobloxResult<Vec<GamePass>> {
+    let games = collect_stream(self.games(GameAccessFilter::All, cookie.clone()).into_stream()).await?;
+
+    let mut gamepasses = Vec::new();
+    for game in games {
+      gamepasses.extend(collect_stream(game.gamepasses(cookie.clone()).into_stream()).await?);
+    }
+
+    Ok(gamepasses)
+  }
 }
@@ -30,12 +30,16 @@
 
 mod asset;
 mod group;
+mod place;
 mod plugin;
+mod private_server;
 mod universe;
 mod user;
 
 pub use asset::Asset as AssetDerive;
 pub use group::Group as GroupDerive;
+pub use place::Place as PlaceDerive;
 pub use plugin::Plugin as PluginDerive;
+pub use private_server::PrivateServer as PrivateServerDerive;
 pub use universe::Universe as UniverseDerive;
 pub use user::User as UserDerive;
@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+
+use crate::{
+  models::{GameServer, PlaceConfiguration, ServerSummary, ServerType, SkinnyPrivateServer},
+  util::{
+    api_helper,
+    paging::{collect_stream, identity_mapper, PageIterator},
+    ResultExtensions, RobloxResult,
+  },
+};
+
+#[async_trait]
+pub trait Place {
+  #[doc(hidden)]
+  fn id(&self) -> i64;
+
+  /// The max players and social slot settings of the place
+  ///
+  /// note: requires a cookie that can edit the place
+  async fn configuration(&self, cookie: Option<String>) -> RobloxResult<PlaceConfiguration> {
+    api_helper::get(format!("https://develop.roblox.com/v2/places/{}", self.id()), cookie)
+      .await
+      .map_async(api_helper::deserialize_body)
+      .await?
+  }
+
+  /// Changes the settings of the place, returning its new configuration. Fields that are `None` are left unchanged
+  async fn update_place(
+    &self,
+    name: Option<&str>,
+    description: Option<&str>,
+    max_players: Option<i64>,
+    cookie: Option<String>,
+  ) -> RobloxResult<PlaceConfiguration> {
+    let mut body = Map::new();
+    if let Some(name) = name {
+      body.insert("name".to_owned(), json!(name));
+    }
+    if let Some(description) = description {
+      body.insert("description".to_owned(), json!(description));
+    }
+    if let Some(max_players) = max_players {
+      body.insert("maxPlayerCount".to_owned(), json!(max_players));
+    }
+
+    api_helper::patch(format!("https://develop.roblox.com/v2/places/{}", self.id()), Value::Object(body), cookie)
+      .await
+      .map_async(api_helper::deserialize_body)
+      .await?
+  }
+
+  fn servers(&self, server_type: ServerType, cookie: Option<String>) -> PageIterator<GameServer, GameServer> {
+    PageIterator::new(
+      format!("https://games.roblox.com/v1/games/{}/servers/{}", self.id(), server_type.serialize()),
+      identity_mapper,
+      cookie,
+    )
+  }
+
+  /// Goes through every server of a type to count the players and find the least full server that can be joined
+  async fn server_summary(&self, server_type: ServerType, cookie: Option<String>) -> RobloxResult<ServerSummary> {
+    let servers = collect_stream(self.servers(server_type, cookie).into_stream()).await?;
+
+    Ok(ServerSummary::from_servers(&servers))
+  }
+
+  fn private_servers(&self, cookie: Option<String>) -> PageIterator<SkinnyPrivateServer, SkinnyPrivateServer> {
+    PageIterator::new(
+      format!("https://games.roblox.com/v1/games/{}/private-servers", self.id()),
+      identity_mapper,
+      cookie,
+    )
+  }
+}
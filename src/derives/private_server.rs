@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+
+use crate::{
+  models::PrivateServer as PrivateServerModel,
+  util::{api_helper, ResultExtensions, RobloxResult},
+};
+
+#[async_trait]
+pub trait PrivateServer {
+  #[doc(hidden)]
+  fn id(&self) -> i64;
+
+  /// note: requires a cookie of the owner of the server
+  async fn details(&self, cookie: Option<String>) -> RobloxResult<PrivateServerModel> {
+    api_helper::get(format!("https://games.roblox.com/v1/vip-servers/{}", self.id()), cookie)
+      .await
+      .map_async(api_helper::deserialize_body)
+      .await?
+  }
+
+  /// Renames, activates or deactivates the server. With `new_join_code`, the link to join the server is replaced
+  /// and the previous one stops working
+  async fn update(&self, name: Option<&str>, active: Option<bool>, new_join_code: bool, cookie: Option<String>) -> RobloxResult<PrivateServerModel> {
+    let mut body = Map::new();
+    if let Some(name) = name {
+      body.insert("name".to_owned(), json!(name));
+    }
+    if let Some(active) = active {
+      body.insert("active".to_owned(), json!(active));
+    }
+    body.insert("newJoinCode".to_owned(), json!(new_join_code));
+
+    api_helper::patch(
+      format!("https://games.roblox.com/v1/vip-servers/{}", self.id()),
+      Value::Object(body),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  async fn set_friends_allowed(&self, friends_allowed: bool, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::patch(
+      format!("https://games.roblox.com/v1/vip-servers/{}/permissions", self.id()),
+      json!({ "friendsAllowed": friends_allowed }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  /// Stops the subscription from renewing. The server stays available until it expires
+  async fn cancel_subscription(&self, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::patch(
+      format!("https://games.roblox.com/v1/vip-servers/{}/subscription", self.id()),
+      json!({ "active": false }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+}
@@ -1,12 +1,18 @@
 use async_trait::async_trait;
 
+use serde_json::{json, Map, Value};
+
 use crate::{
-  models::{Badge, GamePass, SocialLink, UniverseLiveStats},
+  models::{
+    Badge, GamePass, PrivateServer, SkinnyPlace, SkinnyPrivateServer, SocialLink, UniverseConfiguration, UniverseConfigurationUpdate,
+    UniverseLiveStats, UniverseVotes, Vote,
+  },
+  universe_from_id, universe_votes_from_ids,
   util::{
     api_helper,
     paging::{identity_mapper, PageIterator},
-    responses::{ApiArrayResponse, UniverseFavoriteCountResponse},
-    ResultExtensions, RobloxResult,
+    responses::{ApiArrayResponse, UniverseFavoriteCountResponse, UniverseFavoriteResponse},
+    OxidError, ResultExtensions, RobloxResult,
   },
 };
 
@@ -23,6 +29,53 @@ pub trait Universe {
       .map(|data| data.favorites_count)
   }
 
+  /// note: uses [universe_votes_from_ids](crate::universe_votes_from_ids) under the hood
+  async fn votes(&self) -> RobloxResult<UniverseVotes> {
+    universe_votes_from_ids(vec![self.id()])
+      .await?
+      .into_iter()
+      .next()
+      .ok_or(OxidError::Validation("The universe was not found.".to_owned()))
+  }
+
+  async fn vote(&self, vote: Vote, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::patch(
+      format!("https://games.roblox.com/v1/games/{}/user-votes", self.id()),
+      json!({ "vote": vote == Vote::Up }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  async fn is_favorited(&self, cookie: Option<String>) -> RobloxResult<bool> {
+    api_helper::get(format!("https://games.roblox.com/v1/games/{}/favorites", self.id()), cookie)
+      .await
+      .map_async(api_helper::deserialize_body::<UniverseFavoriteResponse>)
+      .await?
+      .map(|data| data.is_favorited)
+  }
+
+  async fn favorite(&self, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!("https://games.roblox.com/v1/games/{}/favorites", self.id()),
+      json!({ "isFavorited": true }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  async fn unfavorite(&self, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!("https://games.roblox.com/v1/games/{}/favorites", self.id()),
+      json!({ "isFavorited": false }),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
   fn badges(&self, cookie: Option<String>) -> PageIterator<Badge, Badge> {
     PageIterator::new(
       format!("https://badges.roblox.com/v1/universes/{}/badges", self.id()),
@@ -53,4 +106,142 @@ pub trait Universe {
       .await?
       .map(|data| data.data)
   }
+
+  async fn configuration(&self, cookie: Option<String>) -> RobloxResult<UniverseConfiguration> {
+    api_helper::get(format!("https://develop.roblox.com/v1/universes/{}/configuration", self.id()), cookie)
+      .await
+      .map_async(api_helper::deserialize_body)
+      .await?
+  }
+
+  /// Changes the settings of the universe, returning its new configuration
+  ///
+  /// note: the max players and copying settings belong to places, so they are set on the root place
+  async fn update_configuration(&self, update: UniverseConfigurationUpdate, cookie: Option<String>) -> RobloxResult<UniverseConfiguration> {
+    if update.max_players.is_some() || update.copying_allowed.is_some() {
+      let root_place_id = universe_from_id(self.id())
+        .await?
+        .ok_or(OxidError::Validation("The universe was not found.".to_owned()))?
+        .root_place_id;
+
+      let mut place = Map::new();
+      if let Some(max_players) = update.max_players {
+        place.insert("maxPlayerCount".to_owned(), json!(max_players));
+      }
+      if let Some(copying_allowed) = update.copying_allowed {
+        place.insert("allowCopying".to_owned(), json!(copying_allowed));
+      }
+
+      api_helper::patch(
+        format!("https://develop.roblox.com/v2/places/{}", root_place_id),
+        Value::Object(place),
+        cookie.clone(),
+      )
+      .await?;
+    }
+
+    let mut universe = Map::new();
+    if let Some(name) = &update.name {
+      universe.insert("name".to_owned(), json!(name));
+    }
+    if let Some(description) = &update.description {
+      universe.insert("description".to_owned(), json!(description));
+    }
+    if let Some(genre) = &update.genre {
+      universe.insert("genre".to_owned(), json!(genre));
+    }
+    if let Some(playable_devices) = &update.playable_devices {
+      let playable_devices = playable_devices.iter().map(|device| device.serialize()).collect::<Vec<_>>();
+      universe.insert("playableDevices".to_owned(), json!(playable_devices));
+    }
+    if let Some(avatar_type) = &update.avatar_type {
+      universe.insert("universeAvatarType".to_owned(), json!(avatar_type.serialize()));
+    }
+    if let Some(allow_private_servers) = update.allow_private_servers {
+      universe.insert("allowPrivateServers".to_owned(), json!(allow_private_servers));
+    }
+    if let Some(private_server_price) = update.private_server_price {
+      universe.insert("privateServerPrice".to_owned(), json!(private_server_price));
+    }
+    if let Some(studio_access_to_apis_allowed) = update.studio_access_to_apis_allowed {
+      universe.insert("studioAccessToApisAllowed".to_owned(), json!(studio_access_to_apis_allowed));
+    }
+
+    api_helper::patch(
+      format!("https://develop.roblox.com/v2/universes/{}/configuration", self.id()),
+      Value::Object(universe),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  /// Makes the universe public, so that it can be played
+  async fn activate(&self, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!("https://develop.roblox.com/v1/universes/{}/activate", self.id()),
+      json!({}),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  /// Makes the universe private
+  async fn deactivate(&self, cookie: Option<String>) -> RobloxResult<()> {
+    api_helper::post(
+      format!("https://develop.roblox.com/v1/universes/{}/deactivate", self.id()),
+      json!({}),
+      cookie,
+    )
+    .await
+    .map(|_| ())
+  }
+
+  fn places(&self, cookie: Option<String>) -> PageIterator<SkinnyPlace, SkinnyPlace> {
+    PageIterator::new(
+      format!("https://develop.roblox.com/v1/universes/{}/places?isUniverseCreation=false", self.id()),
+      identity_mapper,
+      cookie,
+    )
+  }
+
+  /// Lists the private servers of the root place
+  ///
+  /// note: uses [universe_from_id](crate::universe_from_id) to find the root place when the first page is fetched, like
+  /// [PlaceDerive::private_servers](crate::derives::PlaceDerive::private_servers) on the root place
+  fn private_servers(&self, cookie: Option<String>) -> PageIterator<SkinnyPrivateServer, SkinnyPrivateServer> {
+    let universe_id = self.id();
+
+    PageIterator::with_url_resolver(
+      move || {
+        Box::pin(async move {
+          let root_place_id = universe_from_id(universe_id)
+            .await?
+            .ok_or(OxidError::Validation("The universe was not found.".to_owned()))?
+            .root_place_id;
+
+          Ok(format!("https://games.roblox.com/v1/games/{}/private-servers", root_place_id))
+        })
+      },
+      identity_mapper,
+      cookie,
+    )
+  }
+
+  /// Buys a private server for the authenticated user. `expected_price` has to match the current price
+  async fn create_private_server(&self, name: &str, expected_price: i64, cookie: Option<String>) -> RobloxResult<PrivateServer> {
+    api_helper::post(
+      format!("https://games.roblox.com/v1/games/vip-servers/{}", self.id()),
+      json!({
+          "name": name,
+          "expectedPrice": expected_price
+      }),
+      cookie,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
 }
@@ -33,7 +33,7 @@ pub trait User {
         format!("https://premiumfeatures.roblox.com/v1/users/{}/validate-membership", self.id()),
         None,
       )
-      .await?
+      .await?,
     )
     .await
   }
@@ -75,4 +75,12 @@ pub trait User {
       .find(|role| role.group.id == group_id)
       .ok_or(OxidError::Validation("The user was not found in this group.".to_owned()))
   }
+
+  /// The group the user shows on their profile, if any
+  async fn primary_group(&self) -> RobloxResult<Option<UserGroupRolesResponse>> {
+    api_helper::get(format!("https://groups.roblox.com/v1/users/{}/groups/primary/role", self.id()), None)
+      .await
+      .map_async(api_helper::deserialize_body)
+      .await?
+  }
 }
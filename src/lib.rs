@@ -4,6 +4,8 @@ pub mod bases;
 mod client;
 pub mod derives;
 pub mod models;
+pub mod oauth;
+pub mod open_cloud;
 pub mod util;
 
 pub use client::*;
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::util::responses::AuditLogEntryResponse;
+
+use super::Member;
+
+/// The kinds of actions recorded in a group's audit log, used to filter [GroupDerive::audit_log](crate::derives::GroupDerive::audit_log)
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditLogActionType {
+  DeletePost,
+  RemoveMember,
+  AcceptJoinRequest,
+  DeclineJoinRequest,
+  PostStatus,
+  ChangeRank,
+  BuyAd,
+  SendAllyRequest,
+  CreateEnemy,
+  AcceptAllyRequest,
+  DeclineAllyRequest,
+  DeleteAlly,
+  DeleteEnemy,
+  AddGroupPlace,
+  RemoveGroupPlace,
+  CreateItems,
+  ConfigureItems,
+  SpendGroupFunds,
+  ChangeOwner,
+  Delete,
+  AdjustCurrencyAmounts,
+  Abandon,
+  Claim,
+  Rename,
+  ChangeDescription,
+  CreateGroupAsset,
+  UpdateGroupAsset,
+  ConfigureGroupAsset,
+  RevertGroupAsset,
+  CreateGroupDeveloperProduct,
+  ConfigureGroupGame,
+  Lock,
+  Unlock,
+  CreateGamePass,
+  CreateBadge,
+  ConfigureBadge,
+  SavePlace,
+  PublishPlace,
+  UpdateRolesetRank,
+  UpdateRolesetData,
+  BanMember,
+  UnbanMember,
+}
+
+impl AuditLogActionType {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      AuditLogActionType::DeletePost => "DeletePost",
+      AuditLogActionType::RemoveMember => "RemoveMember",
+      AuditLogActionType::AcceptJoinRequest => "AcceptJoinRequest",
+      AuditLogActionType::DeclineJoinRequest => "DeclineJoinRequest",
+      AuditLogActionType::PostStatus => "PostStatus",
+      AuditLogActionType::ChangeRank => "ChangeRank",
+      AuditLogActionType::BuyAd => "BuyAd",
+      AuditLogActionType::SendAllyRequest => "SendAllyRequest",
+      AuditLogActionType::CreateEnemy => "CreateEnemy",
+      AuditLogActionType::AcceptAllyRequest => "AcceptAllyRequest",
+      AuditLogActionType::DeclineAllyRequest => "DeclineAllyRequest",
+      AuditLogActionType::DeleteAlly => "DeleteAlly",
+      AuditLogActionType::DeleteEnemy => "DeleteEnemy",
+      AuditLogActionType::AddGroupPlace => "AddGroupPlace",
+      AuditLogActionType::RemoveGroupPlace => "RemoveGroupPlace",
+      AuditLogActionType::CreateItems => "CreateItems",
+      AuditLogActionType::ConfigureItems => "ConfigureItems",
+      AuditLogActionType::SpendGroupFunds => "SpendGroupFunds",
+      AuditLogActionType::ChangeOwner => "ChangeOwner",
+      AuditLogActionType::Delete => "Delete",
+      AuditLogActionType::AdjustCurrencyAmounts => "AdjustCurrencyAmounts",
+      AuditLogActionType::Abandon => "Abandon",
+      AuditLogActionType::Claim => "Claim",
+      AuditLogActionType::Rename => "Rename",
+      AuditLogActionType::ChangeDescription => "ChangeDescription",
+      AuditLogActionType::CreateGroupAsset => "CreateGroupAsset",
+      AuditLogActionType::UpdateGroupAsset => "UpdateGroupAsset",
+      AuditLogActionType::ConfigureGroupAsset => "ConfigureGroupAsset",
+      AuditLogActionType::RevertGroupAsset => "RevertGroupAsset",
+      AuditLogActionType::CreateGroupDeveloperProduct => "CreateGroupDeveloperProduct",
+      AuditLogActionType::ConfigureGroupGame => "ConfigureGroupGame",
+      AuditLogActionType::Lock => "Lock",
+      AuditLogActionType::Unlock => "Unlock",
+      AuditLogActionType::CreateGamePass => "CreateGamePass",
+      AuditLogActionType::CreateBadge => "CreateBadge",
+      AuditLogActionType::ConfigureBadge => "ConfigureBadge",
+      AuditLogActionType::SavePlace => "SavePlace",
+      AuditLogActionType::PublishPlace => "PublishPlace",
+      AuditLogActionType::UpdateRolesetRank => "UpdateRolesetRank",
+      AuditLogActionType::UpdateRolesetData => "UpdateRolesetData",
+      AuditLogActionType::BanMember => "BanMember",
+      AuditLogActionType::UnbanMember => "UnbanMember",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RankChange {
+  pub target_id: i64,
+  pub target_name: String,
+  pub old_role_set_id: i64,
+  pub old_role_set_name: String,
+  pub new_role_set_id: i64,
+  pub new_role_set_name: String,
+}
+
+/// The payload of actions that target a single member, such as removing or banning them
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct MemberTarget {
+  pub target_id: i64,
+  pub target_name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostDeletion {
+  #[serde(rename = "PostDesc")]
+  pub post_body: String,
+  pub target_id: i64,
+  pub target_name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct StatusPost {
+  pub text: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct FundsSpending {
+  pub amount: i64,
+  #[serde(default)]
+  pub currency_type_name: String,
+  #[serde(default)]
+  pub item_description: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct DescriptionChange {
+  pub new_description: String,
+}
+
+/// The payload of actions on a relationship with another group, such as accepting an ally request
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct GroupTarget {
+  pub target_group_id: i64,
+  pub target_group_name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RolesetRankUpdate {
+  pub role_set_id: i64,
+  pub role_set_name: String,
+  pub old_rank: u8,
+  pub new_rank: u8,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct RolesetDataUpdate {
+  pub role_set_id: i64,
+  pub role_set_name: String,
+  #[serde(default)]
+  pub old_name: String,
+  #[serde(default)]
+  pub new_name: String,
+  #[serde(default)]
+  pub old_description: String,
+  #[serde(default)]
+  pub new_description: String,
+}
+
+/// An audit log action along with its description payload
+#[derive(Debug, Clone)]
+pub enum AuditLogAction {
+  ChangeRank(RankChange),
+  RemoveMember(MemberTarget),
+  AcceptJoinRequest(MemberTarget),
+  DeclineJoinRequest(MemberTarget),
+  BanMember(MemberTarget),
+  UnbanMember(MemberTarget),
+  DeletePost(PostDeletion),
+  PostStatus(StatusPost),
+  SpendGroupFunds(FundsSpending),
+  ChangeDescription(DescriptionChange),
+  SendAllyRequest(GroupTarget),
+  AcceptAllyRequest(GroupTarget),
+  DeclineAllyRequest(GroupTarget),
+  DeleteAlly(GroupTarget),
+  CreateEnemy(GroupTarget),
+  DeleteEnemy(GroupTarget),
+  UpdateRolesetRank(RolesetRankUpdate),
+  UpdateRolesetData(RolesetDataUpdate),
+  /// An action without a typed payload yet, or whose payload didn't match the expected shape
+  Other {
+    action_type: String,
+    description: Value,
+  },
+}
+
+/// Deserializes from the raw audit log entry of the groups API, parsing the description into an [AuditLogAction]
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "AuditLogEntryResponse")]
+pub struct AuditLogEntry {
+  pub actor: Member,
+  pub action: AuditLogAction,
+  pub created: DateTime<Utc>,
+}
@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+  derives::AssetDerive,
+  util::parsers::{parse_cloud_creator, parse_optional_iso8601_date, parse_string_i64},
+};
+
+use super::CloudCreator;
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum CloudAssetType {
+  Audio,
+  Decal,
+  Model,
+  Animation,
+  Video,
+}
+
+impl CloudAssetType {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      CloudAssetType::Audio => "Audio",
+      CloudAssetType::Decal => "Decal",
+      CloudAssetType::Model => "Model",
+      CloudAssetType::Animation => "Animation",
+      CloudAssetType::Video => "Video",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum ModerationState {
+  Reviewing,
+  Rejected,
+  Approved,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationResult {
+  pub moderation_state: ModerationState,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum CloudAssetState {
+  Active,
+  Archived,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreationContext {
+  #[serde(deserialize_with = "parse_cloud_creator")]
+  pub creator: CloudCreator,
+  pub expected_price: Option<i64>,
+}
+
+/// An asset as returned by the Open Cloud assets API
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudAsset {
+  #[serde(rename = "assetId")]
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub id: i64,
+  pub asset_type: CloudAssetType,
+  pub display_name: String,
+  #[serde(default)]
+  pub description: String,
+  pub path: String,
+  pub creation_context: CreationContext,
+  pub revision_id: Option<String>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub revision_create_time: Option<DateTime<Utc>>,
+  pub moderation_result: Option<ModerationResult>,
+  pub state: Option<CloudAssetState>,
+}
+
+impl AssetDerive for CloudAsset {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetVersion {
+  pub path: String,
+  pub creation_context: Option<CreationContext>,
+  pub moderation_result: Option<ModerationResult>,
+  #[serde(default)]
+  pub published: bool,
+}
+
+impl AssetVersion {
+  /// The version number, which is the last segment of the version's path (`assets/{id}/versions/{number}`)
+  pub fn version_number(&self) -> Option<i64> {
+    self.path.rsplit('/').next().and_then(|number| number.parse().ok())
+  }
+}
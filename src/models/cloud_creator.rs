@@ -0,0 +1,19 @@
+use serde_json::{json, Value};
+
+use crate::bases::{BaseGroup, BaseUser};
+
+/// The owner of an Open Cloud resource
+#[derive(Debug, Clone)]
+pub enum CloudCreator {
+  User(BaseUser),
+  Group(BaseGroup),
+}
+
+impl CloudCreator {
+  pub(crate) fn serialize(&self) -> Value {
+    match self {
+      CloudCreator::User(user) => json!({ "userId": user.id.to_string() }),
+      CloudCreator::Group(group) => json!({ "groupId": group.id.to_string() }),
+    }
+  }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+  bases::BaseUser,
+  derives::{GroupDerive, UserDerive},
+  util::parsers::{
+    parse_iso8601_date, parse_optional_iso8601_date, parse_optional_resource_base_user, parse_resource_base_user, parse_resource_id, parse_string_i64,
+  },
+};
+
+/// A group as returned by the Open Cloud groups API
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudGroup {
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub id: i64,
+  pub path: String,
+  pub display_name: String,
+  #[serde(default)]
+  pub description: String,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_resource_base_user")]
+  pub owner: Option<BaseUser>,
+  pub member_count: i64,
+  pub public_entry_allowed: bool,
+  #[serde(default)]
+  pub locked: bool,
+  #[serde(default)]
+  pub verified: bool,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub update_time: DateTime<Utc>,
+}
+
+impl GroupDerive for CloudGroup {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMembership {
+  pub path: String,
+  #[serde(deserialize_with = "parse_resource_base_user")]
+  pub user: BaseUser,
+  #[serde(rename = "role")]
+  #[serde(deserialize_with = "parse_resource_id")]
+  pub role_id: i64,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub create_time: Option<DateTime<Utc>>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub update_time: Option<DateTime<Utc>>,
+}
+
+impl UserDerive for GroupMembership {
+  fn id(&self) -> i64 {
+    self.user.id
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudJoinRequest {
+  pub path: String,
+  #[serde(deserialize_with = "parse_resource_base_user")]
+  pub user: BaseUser,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudGroupShout {
+  pub path: String,
+  #[serde(default)]
+  pub content: String,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_resource_base_user")]
+  pub poster: Option<BaseUser>,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub update_time: DateTime<Utc>,
+}
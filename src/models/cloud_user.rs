@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+  bases::BaseAsset,
+  derives::UserDerive,
+  util::parsers::{parse_iso8601_date, parse_string_i64},
+};
+
+/// A user as returned by the Open Cloud users API
+///
+/// Some fields are only returned when the credential has the required scope,
+/// such as `user.advanced:read` for `premium` and `id_verified`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudUser {
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub id: i64,
+  pub path: String,
+  pub name: String,
+  pub display_name: String,
+  pub about: Option<String>,
+  pub locale: Option<String>,
+  pub premium: Option<bool>,
+  pub id_verified: Option<bool>,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+}
+
+impl UserDerive for CloudUser {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum InventoryItemKind {
+  Asset {
+    asset: BaseAsset,
+    /// e.g. `HAT` or `CLASSIC_SHIRT`
+    asset_type: String,
+    instance_id: Option<i64>,
+  },
+  Badge(i64),
+  GamePass(i64),
+  PrivateServer(i64),
+  /// An item of a kind that isn't supported yet
+  Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct InventoryItem {
+  pub path: String,
+  pub kind: InventoryItemKind,
+  pub add_time: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,113 @@
+use serde::Deserialize;
+
+/// The kind of servers to list, used by [PlaceDerive::servers](crate::derives::PlaceDerive::servers). Private servers
+/// are listed by [PlaceDerive::private_servers](crate::derives::PlaceDerive::private_servers)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerType {
+  Public,
+  /// Servers with friends of the authenticated user in them
+  Friend,
+}
+
+impl ServerType {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      ServerType::Public => "Public",
+      ServerType::Friend => "Friend",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameServer {
+  pub id: String,
+  #[serde(default)]
+  pub playing: i64,
+  pub max_players: i64,
+  pub fps: Option<f64>,
+  pub ping: Option<i64>,
+  /// Tokens used to fetch the avatar thumbnails of the players
+  #[serde(default)]
+  pub player_tokens: Vec<String>,
+}
+
+impl GameServer {
+  pub fn is_full(&self) -> bool {
+    self.playing >= self.max_players
+  }
+}
+
+/// The result of [PlaceDerive::server_summary](crate::derives::PlaceDerive::server_summary)
+#[derive(Debug, Clone)]
+pub struct ServerSummary {
+  pub server_count: usize,
+  pub total_players: i64,
+  /// The server with the fewest players that isn't full
+  pub least_full: Option<GameServer>,
+}
+
+impl ServerSummary {
+  pub(crate) fn from_servers(servers: &[GameServer]) -> Self {
+    ServerSummary {
+      server_count: servers.len(),
+      total_players: servers.iter().map(|server| server.playing).sum(),
+      least_full: servers
+        .iter()
+        .filter(|server| !server.is_full())
+        .min_by_key(|server| server.playing)
+        .cloned(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn server(id: &str, playing: i64, max_players: i64) -> GameServer {
+    serde_json::from_value(json!({
+      "id": id,
+      "playing": playing,
+      "maxPlayers": max_players,
+      "fps": 59.9,
+      "ping": 80,
+      "playerTokens": []
+    }))
+    .expect("Failed to deserialize game server")
+  }
+
+  #[test]
+  fn summary() {
+    let servers = vec![server("a", 10, 10), server("b", 7, 10), server("c", 3, 10), server("d", 12, 12)];
+    let summary = ServerSummary::from_servers(&servers);
+
+    assert_eq!(summary.server_count, 4);
+    assert_eq!(summary.total_players, 32);
+    assert_eq!(summary.least_full.map(|server| server.id), Some("c".to_owned()));
+  }
+
+  #[test]
+  fn summary_of_full_servers() {
+    let servers = vec![server("a", 10, 10), server("b", 12, 12)];
+    let summary = ServerSummary::from_servers(&servers);
+
+    assert_eq!(summary.total_players, 22);
+    assert!(summary.least_full.is_none());
+
+    let summary = ServerSummary::from_servers(&[]);
+    assert_eq!(summary.server_count, 0);
+    assert!(summary.least_full.is_none());
+  }
+
+  #[test]
+  fn missing_playing_count() {
+    let server: GameServer = serde_json::from_value(json!({ "id": "a", "maxPlayers": 10 })).unwrap();
+
+    assert_eq!(server.playing, 0);
+    assert!(!server.is_full());
+  }
+}
@@ -0,0 +1,167 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+  derives::UserDerive,
+  util::{parsers::parse_iso8601_date, OxidError, RobloxResult},
+};
+
+use super::SkinnyUser;
+
+/// The time frame of a [RevenueSummary]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RevenuePeriod {
+  Day,
+  Week,
+  Month,
+  Year,
+}
+
+impl RevenuePeriod {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      RevenuePeriod::Day => "Day",
+      RevenuePeriod::Week => "Week",
+      RevenuePeriod::Month => "Month",
+      RevenuePeriod::Year => "Year",
+    }
+    .to_owned()
+  }
+}
+
+/// Robux earned by a group over a [RevenuePeriod], by source
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RevenueSummary {
+  pub recurring_robux_stipend: i64,
+  pub item_sale_robux: i64,
+  pub purchased_robux: i64,
+  pub trade_system_robux: i64,
+  pub pending_robux: i64,
+  pub group_payout_robux: i64,
+  pub individual_to_group_robux: i64,
+  pub premium_payouts: i64,
+  pub group_premium_payouts: i64,
+  pub adjustment_robux: i64,
+}
+
+/// The kinds of transactions of a group, used to filter [GroupDerive::transactions](crate::derives::GroupDerive::transactions)
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionKind {
+  Sale,
+  Purchase,
+  AffiliateSale,
+  GroupPayout,
+  AdImpressionPayout,
+  PremiumPayout,
+  GroupPremiumPayout,
+  EngagementPayout,
+}
+
+impl TransactionKind {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      TransactionKind::Sale => "Sale",
+      TransactionKind::Purchase => "Purchase",
+      TransactionKind::AffiliateSale => "AffiliateSale",
+      TransactionKind::GroupPayout => "GroupPayout",
+      TransactionKind::AdImpressionPayout => "AdImpressionPayout",
+      TransactionKind::PremiumPayout => "PremiumPayout",
+      TransactionKind::GroupPremiumPayout => "GroupPremiumPayout",
+      TransactionKind::EngagementPayout => "EngagementPayout",
+    }
+    .to_owned()
+  }
+}
+
+/// The user or group on the other side of a transaction
+#[derive(Deserialize, Debug, Clone)]
+pub struct TransactionAgent {
+  pub id: i64,
+  #[serde(rename = "type")]
+  pub agent_type: String,
+  pub name: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TransactionCurrency {
+  pub amount: i64,
+  #[serde(rename = "type")]
+  pub currency_type: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+  pub id: i64,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub created: DateTime<Utc>,
+  pub is_pending: bool,
+  pub agent: TransactionAgent,
+  /// The item the transaction is for. Its shape depends on the kind of transaction
+  pub details: Value,
+  pub currency: TransactionCurrency,
+}
+
+/// A user receiving a recurring share of the group's revenue
+#[derive(Deserialize, Debug, Clone)]
+pub struct PayoutRecipient {
+  pub user: SkinnyUser,
+  pub percentage: i32,
+}
+
+impl UserDerive for PayoutRecipient {
+  fn id(&self) -> i64 {
+    self.user.id
+  }
+}
+
+/// Checks the pairs of user ids and amounts of a one time payout, which must all be positive
+pub(crate) fn validate_payout(recipients: &[(i64, i64)]) -> RobloxResult<()> {
+  if recipients.iter().any(|(_, amount)| *amount <= 0) {
+    return Err(OxidError::Validation("Payout amounts must be positive.".to_owned()));
+  }
+
+  Ok(())
+}
+
+/// Checks the pairs of user ids and percentages of a recurring payout, which can't be negative and can add up to at most 100
+pub(crate) fn validate_recurring_payouts(recipients: &[(i64, i32)]) -> RobloxResult<()> {
+  if recipients.iter().any(|(_, percentage)| *percentage < 0) {
+    return Err(OxidError::Validation("Payout percentages can't be negative.".to_owned()));
+  }
+  // Summed as i64 so that large percentages can't overflow past the check
+  if recipients.iter().map(|(_, percentage)| i64::from(*percentage)).sum::<i64>() > 100 {
+    return Err(OxidError::Validation("Payout percentages add up to more than 100.".to_owned()));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn payout_validation() {
+    assert!(validate_payout(&[(1, 10), (2, 25)]).is_ok());
+    assert!(validate_payout(&[]).is_ok());
+
+    assert!(matches!(validate_payout(&[(1, 10), (2, 0)]), Err(OxidError::Validation(_))));
+    assert!(matches!(validate_payout(&[(1, -5)]), Err(OxidError::Validation(_))));
+  }
+
+  #[test]
+  fn recurring_payout_validation() {
+    assert!(validate_recurring_payouts(&[(1, 60), (2, 40)]).is_ok());
+    assert!(validate_recurring_payouts(&[(1, 0)]).is_ok());
+
+    assert!(matches!(validate_recurring_payouts(&[(1, 60), (2, 41)]), Err(OxidError::Validation(_))));
+    assert!(matches!(validate_recurring_payouts(&[(1, 50), (2, -10)]), Err(OxidError::Validation(_))));
+    assert!(matches!(
+      validate_recurring_payouts(&[(1, i32::MAX), (2, 1)]),
+      Err(OxidError::Validation(_))
+    ));
+  }
+}
@@ -0,0 +1,115 @@
+use serde::Deserialize;
+
+use super::GroupRole;
+
+/// A permission that can be granted to a role, used by [GroupDerive::update_role_permissions](crate::derives::GroupDerive::update_role_permissions)
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupPermission {
+  ViewWall,
+  PostToWall,
+  DeleteFromWall,
+  ViewStatus,
+  PostToStatus,
+  ChangeRank,
+  InviteMembers,
+  RemoveMembers,
+  BanMembers,
+  ManageRelationships,
+  ManageClan,
+  ViewAuditLogs,
+  SpendGroupFunds,
+  AdvertiseGroup,
+  CreateItems,
+  ManageItems,
+  AddGroupPlaces,
+  ManageGroupGames,
+  ViewGroupPayouts,
+  ViewAnalytics,
+}
+
+impl GroupPermission {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      GroupPermission::ViewWall => "ViewWall",
+      GroupPermission::PostToWall => "PostToWall",
+      GroupPermission::DeleteFromWall => "DeleteFromWall",
+      GroupPermission::ViewStatus => "ViewStatus",
+      GroupPermission::PostToStatus => "PostToStatus",
+      GroupPermission::ChangeRank => "ChangeRank",
+      GroupPermission::InviteMembers => "InviteMembers",
+      GroupPermission::RemoveMembers => "RemoveMembers",
+      GroupPermission::BanMembers => "BanMembers",
+      GroupPermission::ManageRelationships => "ManageRelationships",
+      GroupPermission::ManageClan => "ManageClan",
+      GroupPermission::ViewAuditLogs => "ViewAuditLogs",
+      GroupPermission::SpendGroupFunds => "SpendGroupFunds",
+      GroupPermission::AdvertiseGroup => "AdvertiseGroup",
+      GroupPermission::CreateItems => "CreateItems",
+      GroupPermission::ManageItems => "ManageItems",
+      GroupPermission::AddGroupPlaces => "AddGroupPlaces",
+      GroupPermission::ManageGroupGames => "ManageGroupGames",
+      GroupPermission::ViewGroupPayouts => "ViewGroupPayouts",
+      GroupPermission::ViewAnalytics => "ViewAnalytics",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPostsPermissions {
+  pub view_wall: bool,
+  pub post_to_wall: bool,
+  pub delete_from_wall: bool,
+  pub view_status: bool,
+  pub post_to_status: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMembershipPermissions {
+  pub change_rank: bool,
+  pub invite_members: bool,
+  pub remove_members: bool,
+  #[serde(default)]
+  pub ban_members: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupManagementPermissions {
+  pub manage_relationships: bool,
+  pub manage_clan: bool,
+  pub view_audit_logs: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupEconomyPermissions {
+  pub spend_group_funds: bool,
+  pub advertise_group: bool,
+  pub create_items: bool,
+  pub manage_items: bool,
+  pub add_group_places: bool,
+  pub manage_group_games: bool,
+  pub view_group_payouts: bool,
+  #[serde(default)]
+  pub view_analytics: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPermissions {
+  pub group_posts_permissions: GroupPostsPermissions,
+  pub group_membership_permissions: GroupMembershipPermissions,
+  pub group_management_permissions: GroupManagementPermissions,
+  pub group_economy_permissions: GroupEconomyPermissions,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RolePermissions {
+  pub group_id: i64,
+  pub role: GroupRole,
+  pub permissions: GroupPermissions,
+}
@@ -0,0 +1,16 @@
+/// The kind of relationship between two groups
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupRelationshipType {
+  Allies,
+  Enemies,
+}
+
+impl GroupRelationshipType {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      GroupRelationshipType::Allies => "Allies",
+      GroupRelationshipType::Enemies => "Enemies",
+    }
+    .to_owned()
+  }
+}
@@ -1,5 +1,7 @@
 use serde::Deserialize;
 
+use crate::util::{OxidError, RobloxResult};
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GroupRole {
@@ -9,3 +11,169 @@ pub struct GroupRole {
   pub rank: u8,
   pub member_count: Option<i32>,
 }
+
+/// Picks a role of a group, used by [GroupDerive::set_rank](crate::derives::GroupDerive::set_rank)
+#[derive(Debug, Clone)]
+pub enum RoleSelector {
+  Id(i64),
+  Name(String),
+  Rank(u8),
+}
+
+impl RoleSelector {
+  pub(crate) fn matches(&self, role: &GroupRole) -> bool {
+    match self {
+      RoleSelector::Id(id) => role.id == *id,
+      RoleSelector::Name(name) => role.name.eq_ignore_ascii_case(name),
+      RoleSelector::Rank(rank) => role.rank == *rank,
+    }
+  }
+}
+
+/// The role of a member before and after being ranked
+#[derive(Debug, Clone)]
+pub struct RoleChange {
+  pub old_role: GroupRole,
+  pub new_role: GroupRole,
+}
+
+impl RoleChange {
+  /// Moves a member from the role with `current_role_id` to the role matching `selector`, refusing roles at or above `own_rank`
+  pub(crate) fn to_role(roles: &[GroupRole], current_role_id: i64, own_rank: i64, selector: &RoleSelector) -> RobloxResult<RoleChange> {
+    Self::resolve(roles, current_role_id, own_rank, |roles, _| {
+      roles.iter().find(|role| selector.matches(role)).cloned()
+    })
+  }
+
+  /// Moves a member from the role with `current_role_id` to the next role up, refusing roles at or above `own_rank`
+  pub(crate) fn promotion(roles: &[GroupRole], current_role_id: i64, own_rank: i64) -> RobloxResult<RoleChange> {
+    Self::resolve(roles, current_role_id, own_rank, |roles, current| {
+      roles.iter().find(|role| role.rank > current.rank).cloned()
+    })
+  }
+
+  /// Moves a member from the role with `current_role_id` to the next role down, refusing roles at or above `own_rank`.
+  /// Members can't be demoted to the guest role
+  pub(crate) fn demotion(roles: &[GroupRole], current_role_id: i64, own_rank: i64) -> RobloxResult<RoleChange> {
+    Self::resolve(roles, current_role_id, own_rank, |roles, current| {
+      roles.iter().rev().find(|role| role.rank < current.rank && role.rank > 0).cloned()
+    })
+  }
+
+  /// Picks the new role with `select` out of the roles (sorted by rank) and the member's current role
+  fn resolve(
+    roles: &[GroupRole],
+    current_role_id: i64,
+    own_rank: i64,
+    select: impl FnOnce(&[GroupRole], &GroupRole) -> Option<GroupRole>,
+  ) -> RobloxResult<RoleChange> {
+    let mut roles = roles.to_vec();
+    roles.sort_by_key(|role| role.rank);
+
+    let old_role = roles
+      .iter()
+      .find(|role| role.id == current_role_id)
+      .cloned()
+      .ok_or(OxidError::Validation("The role was not found in this group.".to_owned()))?;
+
+    if i64::from(old_role.rank) >= own_rank {
+      return Err(OxidError::Validation("The user's rank is not below your own rank.".to_owned()));
+    }
+
+    let new_role = select(&roles, &old_role).ok_or(OxidError::Validation("The role was not found in this group.".to_owned()))?;
+
+    if i64::from(new_role.rank) >= own_rank {
+      return Err(OxidError::Validation("The role is not below your own rank.".to_owned()));
+    }
+
+    Ok(RoleChange { old_role, new_role })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn role(id: i64, name: &str, rank: u8) -> GroupRole {
+    GroupRole {
+      id,
+      name: name.to_owned(),
+      description: None,
+      rank,
+      member_count: None,
+    }
+  }
+
+  // Unsorted on purpose, the roles endpoint doesn't guarantee an order
+  fn ladder() -> Vec<GroupRole> {
+    vec![
+      role(4, "Admin", 200),
+      role(1, "Guest", 0),
+      role(5, "Owner", 255),
+      role(2, "Member", 1),
+      role(3, "Moderator", 100),
+    ]
+  }
+
+  #[test]
+  fn role_selector() {
+    let moderator = role(3, "Moderator", 100);
+
+    assert!(RoleSelector::Id(3).matches(&moderator));
+    assert!(RoleSelector::Name("moderator".to_owned()).matches(&moderator));
+    assert!(RoleSelector::Rank(100).matches(&moderator));
+    assert!(!RoleSelector::Name("Mod".to_owned()).matches(&moderator));
+    assert!(!RoleSelector::Rank(200).matches(&moderator));
+  }
+
+  #[test]
+  fn set_rank_resolution() {
+    let change = RoleChange::to_role(&ladder(), 2, 255, &RoleSelector::Name("ADMIN".to_owned())).unwrap();
+    assert_eq!(change.old_role.id, 2);
+    assert_eq!(change.new_role.id, 4);
+
+    let change = RoleChange::to_role(&ladder(), 4, 255, &RoleSelector::Rank(1)).unwrap();
+    assert_eq!(change.new_role.id, 2);
+
+    let result = RoleChange::to_role(&ladder(), 2, 255, &RoleSelector::Id(42));
+    assert!(matches!(result, Err(OxidError::Validation(_))));
+
+    // Neither the target role nor the member's current role can be at or above our own rank
+    let result = RoleChange::to_role(&ladder(), 2, 200, &RoleSelector::Id(4));
+    assert!(matches!(result, Err(OxidError::Validation(_))));
+
+    let result = RoleChange::to_role(&ladder(), 4, 200, &RoleSelector::Id(2));
+    assert!(matches!(result, Err(OxidError::Validation(_))));
+  }
+
+  #[test]
+  fn promotion_resolution() {
+    let change = RoleChange::promotion(&ladder(), 2, 255).unwrap();
+    assert_eq!(change.old_role.id, 2);
+    assert_eq!(change.new_role.id, 3);
+
+    let change = RoleChange::promotion(&ladder(), 3, 255).unwrap();
+    assert_eq!(change.new_role.id, 4);
+
+    // The next role up is our own
+    let result = RoleChange::promotion(&ladder(), 3, 200);
+    assert!(matches!(result, Err(OxidError::Validation(_))));
+
+    let result = RoleChange::promotion(&ladder(), 5, 255);
+    assert!(matches!(result, Err(OxidError::Validation(_))));
+  }
+
+  #[test]
+  fn demotion_resolution() {
+    let change = RoleChange::demotion(&ladder(), 4, 255).unwrap();
+    assert_eq!(change.old_role.id, 4);
+    assert_eq!(change.new_role.id, 3);
+
+    // Members are never demoted to the guest role
+    let result = RoleChange::demotion(&ladder(), 2, 255);
+    assert!(matches!(result, Err(OxidError::Validation(_))));
+
+    let result = RoleChange::demotion(&ladder(), 4, 200);
+    assert!(matches!(result, Err(OxidError::Validation(_))));
+  }
+}
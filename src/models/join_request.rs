@@ -1,7 +1,7 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::Deserialize;
 
-use super::SkinnyUser;
+use super::{SkinnyUser, User};
 use crate::util::parsers::parse_iso8601_date;
 
 #[derive(Deserialize, Debug, Clone)]
@@ -10,3 +10,62 @@ pub struct JoinRequest {
   #[serde(deserialize_with = "parse_iso8601_date")]
   pub created: DateTime<Utc>,
 }
+
+/// Whether [GroupDerive::join](crate::derives::GroupDerive::join) made the user a member, or sent a join request
+/// because the group requires approval
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinStatus {
+  Joined,
+  Pending,
+}
+
+/// The rules [GroupDerive::process_join_requests](crate::derives::GroupDerive::process_join_requests) checks
+/// each requester against. A requester has to pass every rule that is set to be accepted
+#[derive(Debug, Clone, Default)]
+pub struct JoinRequestRules {
+  pub min_account_age: Option<Duration>,
+  pub require_verified_badge: bool,
+  /// Requires the requester to be in a group allied with this one
+  pub require_allied_group: bool,
+  pub reject_banned: bool,
+  /// Declines requesters that fail a rule. Otherwise their requests are left pending
+  pub decline_failing: bool,
+}
+
+impl JoinRequestRules {
+  /// The first rule the requester fails, checked in the order of [JoinRequestRejection]. `in_allied_group` is only
+  /// read if [JoinRequestRules::require_allied_group] is set
+  pub fn evaluate(&self, user: &User, in_allied_group: bool) -> Option<JoinRequestRejection> {
+    if self.reject_banned && user.is_banned {
+      Some(JoinRequestRejection::Banned)
+    } else if self
+      .min_account_age
+      .is_some_and(|age| Utc::now().signed_duration_since(user.created) < age)
+    {
+      Some(JoinRequestRejection::AccountTooNew)
+    } else if self.require_verified_badge && !user.has_verified_badge {
+      Some(JoinRequestRejection::NotVerified)
+    } else if self.require_allied_group && !in_allied_group {
+      Some(JoinRequestRejection::NotInAlliedGroup)
+    } else {
+      None
+    }
+  }
+}
+
+/// The first rule a requester failed
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinRequestRejection {
+  Banned,
+  AccountTooNew,
+  NotVerified,
+  NotInAlliedGroup,
+}
+
+/// The result of [GroupDerive::process_join_requests](crate::derives::GroupDerive::process_join_requests)
+#[derive(Debug, Clone, Default)]
+pub struct JoinRequestReport {
+  pub accepted: Vec<SkinnyUser>,
+  /// Requesters that failed a rule, declined only if [JoinRequestRules::decline_failing] is set
+  pub rejected: Vec<(SkinnyUser, JoinRequestRejection)>,
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::util::{
+  paging::{identity_mapper, CloudPageIterator},
+  parsers::{parse_iso8601_date, parse_optional_cloud_duration, parse_optional_iso8601_date},
+  polling::{poll_until_done, Backoff, Pollable},
+  Credential, RobloxResult,
+};
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LuauExecutionState {
+  StateUnspecified,
+  Queued,
+  Processing,
+  Cancelled,
+  Complete,
+  Failed,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LuauExecutionErrorCode {
+  ErrorCodeUnspecified,
+  ScriptError,
+  DeadlineExceeded,
+  OutputSizeLimitExceeded,
+  InternalError,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LuauExecutionError {
+  pub code: LuauExecutionErrorCode,
+  #[serde(default)]
+  pub message: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LuauExecutionOutput {
+  /// The values returned by the script
+  #[serde(default)]
+  pub results: Vec<Value>,
+}
+
+/// A script executed against a place version
+///
+/// Tasks are queued when created. Use [LuauExecutionTask::wait] to poll the
+/// task until it completes, fails or is cancelled.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LuauExecutionTask {
+  pub path: String,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub update_time: Option<DateTime<Utc>>,
+  pub state: LuauExecutionState,
+  #[serde(default)]
+  pub script: String,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_cloud_duration")]
+  pub timeout: Option<Duration>,
+  pub error: Option<LuauExecutionError>,
+  pub output: Option<LuauExecutionOutput>,
+
+  #[serde(skip)]
+  credential: Option<Credential>,
+}
+
+impl Pollable for LuauExecutionTask {
+  fn is_done(&self) -> bool {
+    matches!(
+      self.state,
+      LuauExecutionState::Complete | LuauExecutionState::Failed | LuauExecutionState::Cancelled
+    )
+  }
+}
+
+impl LuauExecutionTask {
+  pub(crate) fn with_credential(mut self, credential: Option<Credential>) -> Self {
+    self.credential = credential;
+    self
+  }
+
+  fn url(&self) -> String {
+    format!("https://apis.roblox.com/cloud/v2/{}", self.path)
+  }
+
+  /// Polls the task with the default [Backoff] until it is done
+  pub async fn wait(self) -> RobloxResult<LuauExecutionTask> {
+    self.wait_with(Backoff::default()).await
+  }
+
+  /// Polls the task with the given [Backoff] until it is done
+  ///
+  /// A task that failed is still returned as `Ok`, with the reason in its `error` field.
+  pub async fn wait_with(self, backoff: Backoff) -> RobloxResult<LuauExecutionTask> {
+    if self.is_done() {
+      return Ok(self);
+    }
+
+    poll_until_done::<LuauExecutionTask>(self.url(), self.credential.clone(), &backoff)
+      .await
+      .map(|task| task.with_credential(self.credential))
+  }
+
+  pub fn logs(&self) -> CloudPageIterator<LuauExecutionTaskLog, LuauExecutionTaskLog> {
+    CloudPageIterator::new(
+      format!("{}/logs?view=STRUCTURED", self.url()),
+      "luauExecutionSessionTaskLogs",
+      identity_mapper,
+      self.credential.clone(),
+    )
+  }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LuauLogMessageType {
+  MessageTypeUnspecified,
+  Output,
+  Info,
+  Warning,
+  Error,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LuauLogMessage {
+  pub message: String,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+  pub message_type: LuauLogMessageType,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LuauExecutionTaskLog {
+  pub path: String,
+  #[serde(default)]
+  pub structured_messages: Vec<LuauLogMessage>,
+}
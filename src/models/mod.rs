@@ -1,47 +1,85 @@
 mod asset_resale_data;
+mod audit_log;
 mod badge;
+mod cloud_asset;
+mod cloud_creator;
+mod cloud_group;
+mod cloud_user;
 mod creator_type;
 mod economy_asset;
+mod game_server;
 mod gamepass;
 mod group;
+mod group_funds;
+mod group_permissions;
+mod group_relationship;
 mod group_role;
 mod group_settings;
 mod group_shout;
 mod join_request;
+mod luau_execution_task;
 mod member;
+mod oauth;
+mod operation;
 mod place;
+mod place_configuration;
 mod plugin;
 mod presence;
 mod previous_usernames_skinny_user;
+mod private_server;
+mod skinny_asset;
 mod skinny_group;
 mod skinny_role;
 mod skinny_universe;
 mod skinny_user;
 mod social_link;
+mod subscription;
 mod universe;
+mod universe_configuration;
+mod universe_votes;
 mod user;
+mod user_restriction;
 mod wall_post;
 
 pub use asset_resale_data::*;
+pub use audit_log::*;
 pub use badge::*;
+pub use cloud_asset::*;
+pub use cloud_creator::CloudCreator;
+pub use cloud_group::*;
+pub use cloud_user::*;
 pub use creator_type::CreatorType;
 pub use economy_asset::*;
+pub use game_server::*;
 pub use gamepass::*;
 pub use group::*;
+pub use group_funds::*;
+pub use group_permissions::*;
+pub use group_relationship::*;
 pub use group_role::*;
 pub use group_settings::*;
 pub use group_shout::*;
 pub use join_request::*;
+pub use luau_execution_task::*;
 pub use member::*;
+pub use oauth::*;
+pub use operation::Operation;
 pub use place::*;
+pub use place_configuration::*;
 pub use plugin::*;
 pub use presence::*;
 pub use previous_usernames_skinny_user::*;
+pub use private_server::*;
+pub use skinny_asset::*;
 pub use skinny_group::*;
 pub use skinny_role::*;
 pub use skinny_universe::*;
 pub use skinny_user::*;
 pub use social_link::*;
+pub use subscription::*;
 pub use universe::*;
+pub use universe_configuration::*;
+pub use universe_votes::*;
 pub use user::*;
+pub use user_restriction::*;
 pub use wall_post::*;
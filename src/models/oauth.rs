@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  bases::{BaseGroup, BaseUniverse, BaseUser},
+  derives::UserDerive,
+  util::{
+    parsers::{parse_optional_unix_timestamp, parse_string_i64},
+    Credential,
+  },
+};
+
+use super::CloudCreator;
+
+/// The tokens issued by the OAuth 2.0 token endpoint
+///
+/// Serializable so that a [TokenStore](crate::oauth::TokenStore) can persist them.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OAuthTokens {
+  pub access_token: String,
+  pub refresh_token: Option<String>,
+  pub token_type: String,
+  /// The granted scopes, separated by spaces
+  pub scope: String,
+  /// Only issued when the `openid` scope is granted
+  pub id_token: Option<String>,
+  pub expires_at: DateTime<Utc>,
+}
+
+impl OAuthTokens {
+  /// A bearer credential for Open Cloud functions
+  pub fn credential(&self) -> Credential {
+    Credential::Bearer(self.access_token.clone())
+  }
+
+  pub fn is_expired(&self) -> bool {
+    self.expires_at <= Utc::now()
+  }
+
+  /// Whether the access token expires within the given margin
+  pub fn expires_within(&self, margin: chrono::Duration) -> bool {
+    self.expires_at <= Utc::now() + margin
+  }
+}
+
+/// The metadata of a token returned by the introspection endpoint. Only `active` is returned for invalid tokens
+#[derive(Deserialize, Debug, Clone)]
+pub struct TokenIntrospection {
+  pub active: bool,
+  pub jti: Option<String>,
+  pub iss: Option<String>,
+  pub token_type: Option<String>,
+  pub client_id: Option<String>,
+  pub aud: Option<String>,
+  /// The id of the user that authorized the token
+  pub sub: Option<String>,
+  pub scope: Option<String>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_unix_timestamp")]
+  pub exp: Option<DateTime<Utc>>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_unix_timestamp")]
+  pub iat: Option<DateTime<Utc>>,
+}
+
+/// The user that authorized an access token. Fields other than `id` require the `profile` scope
+#[derive(Deserialize, Debug, Clone)]
+pub struct OAuthUserInfo {
+  #[serde(rename = "sub")]
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub id: i64,
+  pub name: Option<String>,
+  pub nickname: Option<String>,
+  pub preferred_username: Option<String>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_unix_timestamp")]
+  pub created_at: Option<DateTime<Utc>>,
+  pub profile: Option<String>,
+  pub picture: Option<String>,
+}
+
+impl UserDerive for OAuthUserInfo {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+/// The resources a user granted an app access to, as returned by
+/// [OAuthClient::authorized_resources](crate::oauth::OAuthClient::authorized_resources)
+#[derive(Debug, Clone)]
+pub struct AuthorizedResources {
+  /// The user (or group) that owns the resources
+  pub owner: CloudCreator,
+  pub universes: Vec<BaseUniverse>,
+  /// Creators whose assets can be accessed, e.g. through the `asset:write` scope
+  pub users: Vec<BaseUser>,
+  pub groups: Vec<BaseGroup>,
+}
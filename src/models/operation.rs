@@ -0,0 +1,90 @@
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::util::{
+  api_helper,
+  polling::{poll_until_done, Backoff, Pollable},
+  Credential, OperationError, OxidError, ResultExtensions, RobloxResult,
+};
+
+/// A long-running Open Cloud request
+///
+/// The operation is usually not done when it is returned. Use [Operation::wait]
+/// to poll it until it finishes and get its response.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation<T> {
+  pub path: String,
+  #[serde(default)]
+  pub done: bool,
+  pub response: Option<T>,
+  pub error: Option<OperationError>,
+
+  // The API the operation belongs to, as the path is relative to it
+  #[serde(skip)]
+  base_url: String,
+  #[serde(skip)]
+  credential: Option<Credential>,
+}
+
+impl<T: DeserializeOwned> Pollable for Operation<T> {
+  fn is_done(&self) -> bool {
+    self.done
+  }
+}
+
+impl<T: DeserializeOwned> Operation<T> {
+  pub(crate) fn with_context(mut self, base_url: &str, credential: Option<Credential>) -> Self {
+    self.base_url = base_url.to_owned();
+    self.credential = credential;
+    self
+  }
+
+  /// The id of the operation, which is the last segment of its path
+  pub fn id(&self) -> &str {
+    self.path.rsplit('/').next().unwrap_or_default()
+  }
+
+  fn url(&self) -> String {
+    format!("{}/{}", self.base_url, self.path)
+  }
+
+  /// Fetches the operation once and updates it
+  pub async fn poll(&mut self) -> RobloxResult<()> {
+    let operation = api_helper::cloud_get(self.url(), self.credential.clone())
+      .await
+      .map_async(api_helper::deserialize_body::<Operation<T>>)
+      .await??;
+
+    self.done = operation.done;
+    self.response = operation.response;
+    self.error = operation.error;
+
+    Ok(())
+  }
+
+  /// Polls the operation with the default [Backoff] until it is done
+  pub async fn wait(self) -> RobloxResult<T> {
+    self.wait_with(Backoff::default()).await
+  }
+
+  /// Polls the operation with the given [Backoff] until it is done
+  ///
+  /// Returns [OxidError::Operation] if the operation finished with an error.
+  pub async fn wait_with(self, backoff: Backoff) -> RobloxResult<T> {
+    if self.done {
+      return self.into_result();
+    }
+
+    poll_until_done::<Operation<T>>(self.url(), self.credential.clone(), &backoff)
+      .await?
+      .into_result()
+  }
+
+  fn into_result(self) -> RobloxResult<T> {
+    match (self.response, self.error) {
+      (_, Some(error)) => Err(OxidError::Operation(error)),
+      (Some(response), None) => Ok(response),
+      (None, None) => Err(OxidError::Validation(format!("Operation {} finished without a response", self.path))),
+    }
+  }
+}
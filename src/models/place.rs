@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use crate::{bases::BaseUniverse, util::parsers::parse_base_universe};
+use crate::{bases::BaseUniverse, derives::PlaceDerive, util::parsers::parse_base_universe};
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -40,3 +40,9 @@ pub struct Place {
   pub price: i64,
   pub image_token: String,
 }
+
+impl PlaceDerive for Place {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
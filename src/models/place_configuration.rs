@@ -0,0 +1,52 @@
+use serde::Deserialize;
+
+use crate::{bases::BaseUniverse, derives::PlaceDerive, util::parsers::parse_base_universe};
+
+/// How many slots of a server are kept for friends of the players in it
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum SocialSlotType {
+  Automatic,
+  Empty,
+  Custom,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceConfiguration {
+  pub id: i64,
+  #[serde(rename = "universeId")]
+  #[serde(deserialize_with = "parse_base_universe")]
+  pub universe: BaseUniverse,
+  pub name: String,
+  pub description: String,
+  pub max_player_count: i64,
+  pub social_slot_type: SocialSlotType,
+  /// Only used when the social slot type is [SocialSlotType::Custom]
+  pub custom_social_slots_count: Option<i64>,
+  pub allow_copying: bool,
+  pub is_root_place: bool,
+}
+
+impl PlaceDerive for PlaceConfiguration {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+/// A place of a universe, as listed by [UniverseDerive::places](crate::derives::UniverseDerive::places)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SkinnyPlace {
+  pub id: i64,
+  #[serde(rename = "universeId")]
+  #[serde(deserialize_with = "parse_base_universe")]
+  pub universe: BaseUniverse,
+  pub name: String,
+  pub description: String,
+}
+
+impl PlaceDerive for SkinnyPlace {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
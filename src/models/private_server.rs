@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{derives::PrivateServerDerive, util::parsers::parse_optional_iso8601_date};
+
+use super::SkinnyUser;
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivateServerSubscription {
+  pub active: bool,
+  pub expired: bool,
+  #[serde(default, deserialize_with = "parse_optional_iso8601_date")]
+  pub expiration_date: Option<DateTime<Utc>>,
+  pub price: Option<i64>,
+  pub can_renew: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivateServerPermissions {
+  pub clan_allowed: bool,
+  pub friends_allowed: bool,
+  /// Users that can join without being friends with the owner
+  pub users: Vec<SkinnyUser>,
+}
+
+/// A private server as seen by its owner
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivateServer {
+  pub id: i64,
+  pub name: String,
+  pub join_code: Option<String>,
+  pub link: Option<String>,
+  pub active: bool,
+  pub subscription: PrivateServerSubscription,
+  pub permissions: PrivateServerPermissions,
+}
+
+impl PrivateServerDerive for PrivateServer {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+/// A private server of a place, as listed by [PlaceDerive::private_servers](crate::derives::PlaceDerive::private_servers)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SkinnyPrivateServer {
+  #[serde(rename = "vipServerId")]
+  pub id: i64,
+  pub name: String,
+  pub owner: Option<SkinnyUser>,
+  /// Only returned to users that can join the server
+  pub access_code: Option<String>,
+  pub max_players: i64,
+  #[serde(default)]
+  pub playing: i64,
+}
+
+impl PrivateServerDerive for SkinnyPrivateServer {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+/// A private server the authenticated user owns or has access to, as listed by
+/// [authenticated_private_servers](crate::authenticated_private_servers)
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPrivateServer {
+  #[serde(rename = "privateServerId")]
+  pub id: i64,
+  pub name: String,
+  pub active: bool,
+  pub universe_id: i64,
+  pub universe_name: String,
+  pub place_id: i64,
+  pub owner_id: i64,
+  pub owner_name: String,
+  pub price_in_robux: Option<i64>,
+  #[serde(default, deserialize_with = "parse_optional_iso8601_date")]
+  pub expiration_date: Option<DateTime<Utc>>,
+  pub will_renew: bool,
+}
+
+impl PrivateServerDerive for UserPrivateServer {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+use crate::derives::AssetDerive;
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SkinnyAsset {
+  #[serde(alias = "assetId")]
+  pub id: i64,
+  pub name: String,
+}
+
+impl AssetDerive for SkinnyAsset {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
@@ -23,3 +23,9 @@ impl GroupDerive for SkinnyGroup {
     self.id
   }
 }
+
+impl GroupDerive for SkinnyGroupWithMemberCount {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
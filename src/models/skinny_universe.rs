@@ -15,3 +15,22 @@ impl UniverseDerive for SkinnyUniverse {
     self.id
   }
 }
+
+/// Which games of a creator to list, used by [GroupDerive::games](crate::derives::GroupDerive::games)
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameAccessFilter {
+  All,
+  Public,
+  Private,
+}
+
+impl GameAccessFilter {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      GameAccessFilter::All => "All",
+      GameAccessFilter::Public => "Public",
+      GameAccessFilter::Private => "Private",
+    }
+    .to_owned()
+  }
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+  bases::BaseUser,
+  util::parsers::{parse_iso8601_date, parse_optional_iso8601_date, parse_optional_resource_base_user},
+};
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubscriptionState {
+  StateUnspecified,
+  SubscribedWillRenew,
+  SubscribedWillNotRenew,
+  SubscribedRenewalPaymentPending,
+  Expired,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubscriptionExpirationReason {
+  ExpirationReasonUnspecified,
+  ProductInactive,
+  ProductDeleted,
+  SubscriberCancelled,
+  SubscriberRefunded,
+  Lapsed,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SubscriptionExpirationDetails {
+  pub reason: SubscriptionExpirationReason,
+}
+
+/// A user's subscription to a developer subscription product of a universe
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Subscription {
+  pub path: String,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_resource_base_user")]
+  pub user: Option<BaseUser>,
+  pub active: bool,
+  pub will_renew: bool,
+  pub state: SubscriptionState,
+  pub expiration_details: Option<SubscriptionExpirationDetails>,
+  pub purchase_platform: Option<String>,
+  pub payment_provider: Option<String>,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub update_time: Option<DateTime<Utc>>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub last_billing_time: Option<DateTime<Utc>>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub next_renew_time: Option<DateTime<Utc>>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub expire_time: Option<DateTime<Utc>>,
+}
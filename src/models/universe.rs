@@ -8,7 +8,7 @@ use crate::{
   util::parsers::{parse_iso8601_date, parse_universe_creator},
 };
 
-use super::CreatorType;
+use super::{CreatorType, UniverseConfiguration, UniverseConfigurationUpdate};
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -18,13 +18,24 @@ pub struct UniverseLiveStats {
   pub player_counts_by_device_type: HashMap<String, i64>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub enum UniverseAvatarType {
   MorphToR6,
   MorphToR15,
   PlayerChoice,
 }
 
+impl UniverseAvatarType {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      UniverseAvatarType::MorphToR6 => "MorphToR6",
+      UniverseAvatarType::MorphToR15 => "MorphToR15",
+      UniverseAvatarType::PlayerChoice => "PlayerChoice",
+    }
+    .to_owned()
+  }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Universe {
@@ -58,10 +69,33 @@ pub struct Universe {
   pub universe_avatar_type: UniverseAvatarType,
   pub genre: String,
   pub is_all_genre: bool,
-  // isFavoritedByUser
+  /// Only true when fetched with the cookie of a user that favorited the universe
+  #[serde(default)]
+  pub is_favorited_by_user: bool,
   pub favorited_count: i64,
 }
 
+impl Universe {
+  /// Updates the fields of this universe after [UniverseDerive::update_configuration], with the update that was sent
+  /// and the configuration it returned
+  pub fn apply_configuration(&mut self, update: &UniverseConfigurationUpdate, configuration: &UniverseConfiguration) {
+    self.name = configuration.name.clone();
+    self.genre = configuration.genre.clone();
+    self.universe_avatar_type = configuration.universe_avatar_type.clone();
+    self.studio_access_to_apis_allowed = configuration.studio_access_to_apis_allowed;
+    self.create_vip_servers_allowed = configuration.allow_private_servers;
+    if let Some(description) = &update.description {
+      self.description = Some(description.clone());
+    }
+    if let Some(max_players) = update.max_players {
+      self.max_players = max_players;
+    }
+    if let Some(copying_allowed) = update.copying_allowed {
+      self.copying_allowed = copying_allowed;
+    }
+  }
+}
+
 impl UniverseDerive for Universe {
   fn id(&self) -> i64 {
     self.id
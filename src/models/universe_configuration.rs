@@ -0,0 +1,61 @@
+use serde::Deserialize;
+
+use super::UniverseAvatarType;
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum PlayableDevice {
+  Computer,
+  Phone,
+  Tablet,
+  Console,
+  VR,
+}
+
+impl PlayableDevice {
+  pub(crate) fn serialize(&self) -> String {
+    match self {
+      PlayableDevice::Computer => "Computer",
+      PlayableDevice::Phone => "Phone",
+      PlayableDevice::Tablet => "Tablet",
+      PlayableDevice::Console => "Console",
+      PlayableDevice::VR => "VR",
+    }
+    .to_owned()
+  }
+}
+
+/// The settings of a universe, as seen on the Creator Dashboard
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniverseConfiguration {
+  pub id: i64,
+  pub name: String,
+  pub universe_avatar_type: UniverseAvatarType,
+  pub genre: String,
+  pub playable_devices: Vec<PlayableDevice>,
+  pub allow_private_servers: bool,
+  pub private_server_price: Option<i64>,
+  pub is_friends_only: Option<bool>,
+  pub is_archived: bool,
+  pub is_for_sale: bool,
+  pub price: Option<i64>,
+  pub studio_access_to_apis_allowed: bool,
+}
+
+/// The settings to change with [UniverseDerive::update_configuration](crate::derives::UniverseDerive::update_configuration).
+/// Fields that are `None` are left unchanged
+#[derive(Debug, Clone, Default)]
+pub struct UniverseConfigurationUpdate {
+  pub name: Option<String>,
+  pub description: Option<String>,
+  pub genre: Option<String>,
+  pub playable_devices: Option<Vec<PlayableDevice>>,
+  pub avatar_type: Option<UniverseAvatarType>,
+  /// Set on the root place, as it is a setting of places
+  pub max_players: Option<i64>,
+  pub allow_private_servers: Option<bool>,
+  pub private_server_price: Option<i64>,
+  pub studio_access_to_apis_allowed: Option<bool>,
+  /// Set on the root place, as it is a setting of places
+  pub copying_allowed: Option<bool>,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+use crate::derives::UniverseDerive;
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniverseVotes {
+  pub id: i64,
+  pub up_votes: i64,
+  pub down_votes: i64,
+}
+
+impl UniverseDerive for UniverseVotes {
+  fn id(&self) -> i64 {
+    self.id
+  }
+}
+
+/// A vote on a universe, used by [UniverseDerive::vote](crate::derives::UniverseDerive::vote)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Vote {
+  Up,
+  Down,
+}
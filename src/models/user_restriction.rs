@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+  bases::BaseUser,
+  util::parsers::{
+    parse_iso8601_date, parse_optional_cloud_duration, parse_optional_iso8601_date, parse_optional_resource_id, parse_resource_base_user,
+    parse_restriction_moderator,
+  },
+};
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameJoinRestriction {
+  pub active: bool,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub start_time: Option<DateTime<Utc>>,
+  /// `None` for permanent restrictions
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_cloud_duration")]
+  pub duration: Option<Duration>,
+  #[serde(default)]
+  pub private_reason: String,
+  #[serde(default)]
+  pub display_reason: String,
+  #[serde(default)]
+  pub exclude_alt_accounts: bool,
+  /// Whether the restriction is inherited from the universe, for restrictions of a place
+  #[serde(default)]
+  pub inherited: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRestriction {
+  pub path: String,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub update_time: Option<DateTime<Utc>>,
+  #[serde(deserialize_with = "parse_resource_base_user")]
+  pub user: BaseUser,
+  pub game_join_restriction: GameJoinRestriction,
+}
+
+#[derive(Debug, Clone)]
+pub enum RestrictionModerator {
+  User(BaseUser),
+  /// The restriction was changed by a script running in a game server
+  GameServerScript,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRestrictionLog {
+  #[serde(deserialize_with = "parse_resource_base_user")]
+  pub user: BaseUser,
+  /// The place the restriction applies to, `None` if it applies to the whole universe
+  #[serde(rename = "place")]
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_resource_id")]
+  pub place_id: Option<i64>,
+  #[serde(deserialize_with = "parse_restriction_moderator")]
+  pub moderator: RestrictionModerator,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub create_time: DateTime<Utc>,
+  pub active: bool,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_iso8601_date")]
+  pub start_time: Option<DateTime<Utc>>,
+  #[serde(default)]
+  #[serde(deserialize_with = "parse_optional_cloud_duration")]
+  pub duration: Option<Duration>,
+  #[serde(default)]
+  pub private_reason: String,
+  #[serde(default)]
+  pub display_reason: String,
+  #[serde(default)]
+  pub exclude_alt_accounts: bool,
+}
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
-use crate::util::parsers::parse_iso8601_date;
+use crate::util::{parsers::parse_iso8601_date, OxidError};
 
 use super::Member;
 
@@ -15,3 +15,46 @@ pub struct WallPost {
   #[serde(deserialize_with = "parse_iso8601_date")]
   pub updated: DateTime<Utc>,
 }
+
+/// Which posts [GroupDerive::purge_wall_posts](crate::derives::GroupDerive::purge_wall_posts) deletes. A post
+/// has to match every filter that is set
+#[derive(Debug, Clone, Default)]
+pub struct WallPostFilter {
+  /// Matches posts containing the keyword, ignoring case
+  pub keyword: Option<String>,
+  pub user_id: Option<i64>,
+  pub created_before: Option<DateTime<Utc>>,
+  pub created_after: Option<DateTime<Utc>>,
+}
+
+impl WallPostFilter {
+  pub fn matches(&self, post: &WallPost) -> bool {
+    let keyword = self
+      .keyword
+      .as_ref()
+      .is_none_or(|keyword| post.body.to_lowercase().contains(&keyword.to_lowercase()));
+    let user = self
+      .user_id
+      .is_none_or(|user_id| post.poster.as_ref().is_some_and(|poster| poster.user.id == user_id));
+    let before = self.created_before.is_none_or(|date| post.created < date);
+    let after = self.created_after.is_none_or(|date| post.created > date);
+
+    keyword && user && before && after
+  }
+}
+
+/// A wall post that matched a purge but couldn't be deleted
+#[derive(Debug)]
+pub struct WallPostDeletionFailure {
+  pub post: WallPost,
+  pub error: OxidError,
+}
+
+/// The result of [GroupDerive::purge_wall_posts](crate::derives::GroupDerive::purge_wall_posts)
+#[derive(Debug, Default)]
+pub struct WallPurgeReport {
+  pub dry_run: bool,
+  /// Every post that matched the filter, including the ones that failed to be deleted
+  pub matched: Vec<WallPost>,
+  pub failed: Vec<WallPostDeletionFailure>,
+}
@@ -0,0 +1,197 @@
+use reqwest::{Method, Url};
+
+use crate::{
+  models::{AuthorizedResources, OAuthTokens, OAuthUserInfo, TokenIntrospection},
+  util::{
+    api_helper::{self, CloudBody},
+    responses::{OAuthTokenResponse, TokenResourcesResponse},
+    Credential, OxidError, ResultExtensions, RobloxResult,
+  },
+};
+
+use super::Pkce;
+
+const OAUTH_API: &str = "https://apis.roblox.com/oauth/v1";
+
+/// Builds the URL users are sent to in order to authorize the app
+#[derive(Debug, Clone)]
+pub struct AuthorizationUrl {
+  client_id: String,
+  redirect_uri: String,
+  scopes: Vec<String>,
+  state: Option<String>,
+  code_challenge: Option<String>,
+  nonce: Option<String>,
+  prompt: Option<String>,
+}
+
+impl AuthorizationUrl {
+  pub fn scopes(mut self, scopes: &[&str]) -> Self {
+    self.scopes = scopes.iter().map(|scope| scope.to_string()).collect();
+    self
+  }
+
+  /// An opaque value returned unchanged with the redirect, used to protect against CSRF
+  pub fn state(mut self, state: &str) -> Self {
+    self.state = Some(state.to_owned());
+    self
+  }
+
+  pub fn pkce(mut self, pkce: &Pkce) -> Self {
+    self.code_challenge = Some(pkce.challenge.clone());
+    self
+  }
+
+  /// A value included in the ID token, used to protect against replay attacks
+  pub fn nonce(mut self, nonce: &str) -> Self {
+    self.nonce = Some(nonce.to_owned());
+    self
+  }
+
+  /// Space separated prompts to show, e.g. `login consent`
+  pub fn prompt(mut self, prompt: &str) -> Self {
+    self.prompt = Some(prompt.to_owned());
+    self
+  }
+
+  pub fn build(&self) -> String {
+    let mut url = Url::parse(&format!("{}/authorize", OAUTH_API)).unwrap();
+
+    {
+      let mut query = url.query_pairs_mut();
+      query
+        .append_pair("client_id", &self.client_id)
+        .append_pair("redirect_uri", &self.redirect_uri)
+        .append_pair("scope", &self.scopes.join(" "))
+        .append_pair("response_type", "code");
+
+      if let Some(state) = &self.state {
+        query.append_pair("state", state);
+      }
+      if let Some(code_challenge) = &self.code_challenge {
+        query
+          .append_pair("code_challenge", code_challenge)
+          .append_pair("code_challenge_method", "S256");
+      }
+      if let Some(nonce) = &self.nonce {
+        query.append_pair("nonce", nonce);
+      }
+      if let Some(prompt) = &self.prompt {
+        query.append_pair("prompt", prompt);
+      }
+    }
+
+    url.to_string()
+  }
+}
+
+/// An OAuth 2.0 app registered on the Creator Dashboard
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+  client_id: String,
+  /// `None` for public clients, which have to use PKCE instead
+  client_secret: Option<String>,
+  redirect_uri: String,
+}
+
+impl OAuthClient {
+  pub fn new(client_id: &str, client_secret: Option<&str>, redirect_uri: &str) -> Self {
+    Self {
+      client_id: client_id.to_owned(),
+      client_secret: client_secret.map(|secret| secret.to_owned()),
+      redirect_uri: redirect_uri.to_owned(),
+    }
+  }
+
+  pub fn authorization_url(&self) -> AuthorizationUrl {
+    AuthorizationUrl {
+      client_id: self.client_id.clone(),
+      redirect_uri: self.redirect_uri.clone(),
+      scopes: vec!["openid".to_owned()],
+      state: None,
+      code_challenge: None,
+      nonce: None,
+      prompt: None,
+    }
+  }
+
+  // The client authenticates through the body of each request rather than a header
+  fn form(&self, fields: &[(&str, &str)]) -> CloudBody {
+    let mut form = vec![("client_id".to_owned(), self.client_id.clone())];
+    if let Some(client_secret) = &self.client_secret {
+      form.push(("client_secret".to_owned(), client_secret.clone()));
+    }
+    form.extend(fields.iter().map(|(key, value)| (key.to_string(), value.to_string())));
+
+    CloudBody::Form(form)
+  }
+
+  async fn request_tokens(&self, fields: &[(&str, &str)]) -> RobloxResult<OAuthTokens> {
+    api_helper::send_cloud_request(Method::POST, format!("{}/token", OAUTH_API), self.form(fields), None)
+      .await
+      .map_async(api_helper::deserialize_body::<OAuthTokenResponse>)
+      .await?
+      .map(|data| data.into_tokens())
+  }
+
+  /// Exchanges the code Roblox redirected back with for tokens. `code_verifier` is required if PKCE was used
+  pub async fn exchange_code(&self, code: &str, code_verifier: Option<&str>) -> RobloxResult<OAuthTokens> {
+    let mut fields = vec![("grant_type", "authorization_code"), ("code", code), ("redirect_uri", &self.redirect_uri)];
+    if let Some(code_verifier) = code_verifier {
+      fields.push(("code_verifier", code_verifier));
+    }
+
+    self.request_tokens(&fields).await
+  }
+
+  /// Issues new tokens from a refresh token. Roblox rotates refresh tokens, so the old one can't be used again
+  pub async fn refresh(&self, refresh_token: &str) -> RobloxResult<OAuthTokens> {
+    self
+      .request_tokens(&[("grant_type", "refresh_token"), ("refresh_token", refresh_token)])
+      .await
+  }
+
+  pub async fn introspect(&self, token: &str) -> RobloxResult<TokenIntrospection> {
+    api_helper::send_cloud_request(
+      Method::POST,
+      format!("{}/token/introspect", OAUTH_API),
+      self.form(&[("token", token)]),
+      None,
+    )
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+  }
+
+  /// Lists the resources the user granted access to when authorizing the token, one entry per owner
+  pub async fn authorized_resources(&self, token: &str) -> RobloxResult<Vec<AuthorizedResources>> {
+    api_helper::send_cloud_request(
+      Method::POST,
+      format!("{}/token/resources", OAUTH_API),
+      self.form(&[("token", token)]),
+      None,
+    )
+    .await
+    .map_async(api_helper::deserialize_body::<TokenResourcesResponse>)
+    .await??
+    .resource_infos
+    .into_iter()
+    .map(|info| info.into_resources().map_err(|e| OxidError::Validation(e.to_string())))
+    .collect()
+  }
+
+  /// Revokes a refresh token along with the access tokens issued from it
+  pub async fn revoke(&self, token: &str) -> RobloxResult<()> {
+    api_helper::send_cloud_request(Method::POST, format!("{}/token/revoke", OAUTH_API), self.form(&[("token", token)]), None)
+      .await
+      .map(|_| ())
+  }
+}
+
+/// Fetches the user that authorized the access token
+pub async fn user_info(credential: Option<Credential>) -> RobloxResult<OAuthUserInfo> {
+  api_helper::cloud_get(format!("{}/userinfo", OAUTH_API), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
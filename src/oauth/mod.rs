@@ -0,0 +1,44 @@
+//! Functions for the OAuth 2.0 API
+//!
+//! Implements the authorization code flow used by "Login with Roblox". The
+//! user is sent to the URL built by [OAuthClient::authorization_url], and the
+//! code Roblox redirects back with is exchanged for tokens:
+//!
+//! ```rust
+//! let client = OAuthClient::new("client id", Some("client secret"), "https://example.com/callback");
+//! let pkce = Pkce::new();
+//!
+//! let url = client
+//!     .authorization_url()
+//!     .scopes(&["openid", "profile"])
+//!     .state("random state")
+//!     .pkce(&pkce)
+//!     .build();
+//!
+//! // ...after the user is redirected back with a code
+//! let tokens = client.exchange_code(&code, Some(&pkce.verifier)).await.unwrap();
+//! let user = oxid_roblox::oauth::user_info(Some(tokens.credential())).await.unwrap();
+//! ```
+//!
+//! The access token can then be used with any [open_cloud](crate::open_cloud)
+//! function through [OAuthTokens::credential](crate::models::OAuthTokens::credential),
+//! or set for every request with [set_access_token](crate::set_access_token).
+//!
+//! Access tokens expire after 15 minutes. To have them refreshed
+//! automatically, give the tokens to a [TokenStore] and set it with
+//! [set_token_store](crate::set_token_store). Open Cloud requests without
+//! an explicit credential then use the stored access token, refreshing it
+//! shortly before it expires or when Roblox rejects it:
+//!
+//! ```rust
+//! oxid_roblox::set_token_store(client, FileTokenStore::new("tokens.json"));
+//! ```
+
+mod client;
+mod pkce;
+mod token_store;
+
+pub use client::*;
+pub use pkce::Pkce;
+pub(crate) use token_store::TokenRefresher;
+pub use token_store::{FileTokenStore, MemoryTokenStore, TokenStore};
@@ -0,0 +1,38 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// A Proof Key for Code Exchange (RFC 7636) pair
+///
+/// The challenge is sent in the authorization URL and the verifier when
+/// exchanging the code, proving both requests come from the same client.
+/// The verifier has to be kept (e.g. in the user's session) between them.
+#[derive(Debug, Clone)]
+pub struct Pkce {
+  pub verifier: String,
+  pub challenge: String,
+}
+
+impl Default for Pkce {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Pkce {
+  /// Generates a random verifier and its S256 challenge
+  pub fn new() -> Self {
+    let mut bytes = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut bytes);
+
+    Self::from_verifier(&URL_SAFE_NO_PAD.encode(bytes))
+  }
+
+  /// Computes the S256 challenge of an existing verifier
+  pub fn from_verifier(verifier: &str) -> Self {
+    Self {
+      verifier: verifier.to_owned(),
+      challenge: URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes())),
+    }
+  }
+}
@@ -0,0 +1,145 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Mutex,
+};
+
+use async_trait::async_trait;
+
+use crate::{
+  models::OAuthTokens,
+  util::{Credential, OxidError, RobloxResult},
+};
+
+use super::OAuthClient;
+
+/// How long before expiry an access token is refreshed
+const REFRESH_MARGIN_SECONDS: i64 = 60;
+
+/// Storage for the tokens of a user, consulted by Open Cloud requests once set
+/// through [set_token_store](crate::set_token_store)
+///
+/// Refreshed tokens are saved back to the store, as Roblox rotates refresh tokens
+/// and the previous one can't be used again.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+  async fn load(&self) -> RobloxResult<Option<OAuthTokens>>;
+  async fn save(&self, tokens: &OAuthTokens) -> RobloxResult<()>;
+}
+
+/// Keeps tokens in memory. They are lost when the program exits
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+  tokens: Mutex<Option<OAuthTokens>>,
+}
+
+impl MemoryTokenStore {
+  pub fn new(tokens: OAuthTokens) -> Self {
+    Self {
+      tokens: Mutex::new(Some(tokens)),
+    }
+  }
+}
+
+#[async_trait]
+impl TokenStore for MemoryTokenStore {
+  async fn load(&self) -> RobloxResult<Option<OAuthTokens>> {
+    Ok(self.tokens.lock().unwrap().clone())
+  }
+
+  async fn save(&self, tokens: &OAuthTokens) -> RobloxResult<()> {
+    *self.tokens.lock().unwrap() = Some(tokens.clone());
+    Ok(())
+  }
+}
+
+/// Keeps tokens in a json file, so they survive restarts
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+  path: PathBuf,
+}
+
+impl FileTokenStore {
+  pub fn new(path: impl AsRef<Path>) -> Self {
+    Self {
+      path: path.as_ref().to_path_buf(),
+    }
+  }
+}
+
+#[async_trait]
+impl TokenStore for FileTokenStore {
+  /// Returns `Ok(None)` if the file doesn't exist yet
+  async fn load(&self) -> RobloxResult<Option<OAuthTokens>> {
+    match tokio::fs::read_to_string(&self.path).await {
+      Ok(contents) => serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|e| OxidError::Validation(e.to_string())),
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+      Err(e) => Err(OxidError::Validation(e.to_string())),
+    }
+  }
+
+  async fn save(&self, tokens: &OAuthTokens) -> RobloxResult<()> {
+    let contents = serde_json::to_string(tokens).map_err(|e| OxidError::Validation(e.to_string()))?;
+    tokio::fs::write(&self.path, contents)
+      .await
+      .map_err(|e| OxidError::Validation(e.to_string()))
+  }
+}
+
+/// Hands out access tokens from a store, refreshing them when needed
+pub(crate) struct TokenRefresher {
+  client: OAuthClient,
+  store: Box<dyn TokenStore>,
+  // The tokens last loaded or saved, so that the store is only used when refreshing
+  cached: Mutex<Option<OAuthTokens>>,
+  // Held while refreshing so that concurrent requests don't use the same refresh token twice
+  refresh_lock: tokio::sync::Mutex<()>,
+}
+
+impl TokenRefresher {
+  pub(crate) fn new(client: OAuthClient, store: Box<dyn TokenStore>) -> Self {
+    Self {
+      client,
+      store,
+      cached: Mutex::new(None),
+      refresh_lock: tokio::sync::Mutex::new(()),
+    }
+  }
+
+  fn needs_refresh(tokens: &OAuthTokens, rejected_token: Option<&str>) -> bool {
+    tokens.expires_within(chrono::Duration::seconds(REFRESH_MARGIN_SECONDS)) || rejected_token == Some(tokens.access_token.as_str())
+  }
+
+  /// Returns a bearer credential, refreshing the tokens first if they are about to expire or if
+  /// `rejected_token` (an access token the API answered 401 to) is still the current one
+  pub(crate) async fn credential(&self, rejected_token: Option<&str>) -> RobloxResult<Credential> {
+    if let Some(tokens) = self.cached.lock().unwrap().as_ref() {
+      if !Self::needs_refresh(tokens, rejected_token) {
+        return Ok(tokens.credential());
+      }
+    }
+
+    let _guard = self.refresh_lock.lock().await;
+
+    // Another request may have refreshed the tokens while this one waited for the lock
+    let cached = self.cached.lock().unwrap().clone();
+    let tokens = match cached {
+      Some(tokens) => tokens,
+      None => self.store.load().await?.ok_or(OxidError::Unauthorized)?,
+    };
+
+    let tokens = match (&tokens.refresh_token, Self::needs_refresh(&tokens, rejected_token)) {
+      (Some(refresh_token), true) => {
+        let refreshed = self.client.refresh(refresh_token).await?;
+        self.store.save(&refreshed).await?;
+        refreshed
+      }
+      _ => tokens,
+    };
+
+    *self.cached.lock().unwrap() = Some(tokens.clone());
+
+    Ok(tokens.credential())
+  }
+}
@@ -0,0 +1,186 @@
+use reqwest::Method;
+use serde_json::{json, Map, Value};
+
+use crate::{
+  models::{AssetVersion, CloudAsset, CloudAssetType, CloudCreator, Operation},
+  util::{
+    api_helper::{self, CloudBody},
+    multipart::MultipartForm,
+    paging::{identity_mapper, CloudPageIterator},
+    Credential, ResultExtensions, RobloxResult,
+  },
+};
+
+const ASSETS_API: &str = "https://apis.roblox.com/assets/v1";
+
+/// The content of an asset to upload
+#[derive(Debug, Clone)]
+pub struct AssetFile {
+  pub name: String,
+  pub content_type: String,
+  pub content: Vec<u8>,
+}
+
+impl AssetFile {
+  /// Creates a file, detecting its content type from the extension of `name`
+  pub fn new(name: &str, content: Vec<u8>) -> Self {
+    Self {
+      name: name.to_owned(),
+      content_type: content_type_from_name(name).to_owned(),
+      content,
+    }
+  }
+}
+
+fn content_type_from_name(name: &str) -> &'static str {
+  match name.rsplit('.').next().unwrap_or_default().to_lowercase().as_str() {
+    "mp3" => "audio/mpeg",
+    "ogg" => "audio/ogg",
+    "wav" => "audio/wav",
+    "flac" => "audio/flac",
+    "png" => "image/png",
+    "jpg" | "jpeg" => "image/jpeg",
+    "bmp" => "image/bmp",
+    "tga" => "image/tga",
+    "fbx" => "model/fbx",
+    "gltf" => "model/gltf+json",
+    "glb" => "model/gltf-binary",
+    "rbxm" => "model/x-rbxm",
+    "mp4" => "video/mp4",
+    "mov" => "video/mov",
+    _ => "application/octet-stream",
+  }
+}
+
+async fn send_asset_request(
+  verb: Method,
+  url: String,
+  request: Value,
+  file: Option<AssetFile>,
+  credential: Option<Credential>,
+) -> RobloxResult<Operation<CloudAsset>> {
+  let mut form = MultipartForm::new().text("request", &request.to_string());
+  if let Some(file) = file {
+    form = form.file("fileContent", &file.name, &file.content_type, &file.content);
+  }
+  let (content_type, bytes) = form.build();
+
+  api_helper::cloud_request(verb, url, CloudBody::Raw { content_type, bytes }, credential.clone())
+    .await
+    .map_async(api_helper::deserialize_body::<Operation<CloudAsset>>)
+    .await?
+    .map(|operation| operation.with_context(ASSETS_API, credential))
+}
+
+pub async fn create_asset(
+  asset_type: CloudAssetType,
+  display_name: &str,
+  description: &str,
+  creator: CloudCreator,
+  file: AssetFile,
+  credential: Option<Credential>,
+) -> RobloxResult<Operation<CloudAsset>> {
+  send_asset_request(
+    Method::POST,
+    format!("{}/assets", ASSETS_API),
+    json!({
+        "assetType": asset_type.serialize(),
+        "displayName": display_name,
+        "description": description,
+        "creationContext": {
+            "creator": creator.serialize()
+        }
+    }),
+    Some(file),
+    credential,
+  )
+  .await
+}
+
+/// Updates the metadata and/or the content of an asset. Fields that are `None` are left unchanged
+pub async fn update_asset(
+  asset_id: i64,
+  display_name: Option<&str>,
+  description: Option<&str>,
+  file: Option<AssetFile>,
+  credential: Option<Credential>,
+) -> RobloxResult<Operation<CloudAsset>> {
+  let mut request = Map::new();
+  let mut update_mask = Vec::new();
+  request.insert("assetId".to_owned(), json!(asset_id.to_string()));
+  if let Some(display_name) = display_name {
+    request.insert("displayName".to_owned(), json!(display_name));
+    update_mask.push("displayName");
+  }
+  if let Some(description) = description {
+    request.insert("description".to_owned(), json!(description));
+    update_mask.push("description");
+  }
+
+  // Only the content changes without an update mask
+  let url = if update_mask.is_empty() {
+    format!("{}/assets/{}", ASSETS_API, asset_id)
+  } else {
+    format!("{}/assets/{}?updateMask={}", ASSETS_API, asset_id, update_mask.join(","))
+  };
+
+  send_asset_request(Method::PATCH, url, Value::Object(request), file, credential).await
+}
+
+pub async fn asset_from_id(asset_id: i64, credential: Option<Credential>) -> RobloxResult<CloudAsset> {
+  api_helper::cloud_get(format!("{}/assets/{}", ASSETS_API, asset_id), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
+
+pub fn asset_versions(asset_id: i64, credential: Option<Credential>) -> CloudPageIterator<AssetVersion, AssetVersion> {
+  CloudPageIterator::new(
+    format!("{}/assets/{}/versions", ASSETS_API, asset_id),
+    "assetVersions",
+    identity_mapper,
+    credential,
+  )
+}
+
+pub async fn asset_version(asset_id: i64, version_number: i64, credential: Option<Credential>) -> RobloxResult<AssetVersion> {
+  api_helper::cloud_get(format!("{}/assets/{}/versions/{}", ASSETS_API, asset_id, version_number), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
+
+/// Creates a new version of the asset with the content of an older version
+pub async fn rollback_asset(asset_id: i64, version_number: i64, credential: Option<Credential>) -> RobloxResult<AssetVersion> {
+  api_helper::cloud_post(
+    format!("{}/assets/{}/versions:rollback", ASSETS_API, asset_id),
+    json!({ "assetVersion": format!("assets/{}/versions/{}", asset_id, version_number) }),
+    credential,
+  )
+  .await
+  .map_async(api_helper::deserialize_body)
+  .await?
+}
+
+pub async fn archive_asset(asset_id: i64, credential: Option<Credential>) -> RobloxResult<CloudAsset> {
+  api_helper::cloud_post(format!("{}/assets/{}:archive", ASSETS_API, asset_id), json!({}), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
+
+pub async fn restore_asset(asset_id: i64, credential: Option<Credential>) -> RobloxResult<CloudAsset> {
+  api_helper::cloud_post(format!("{}/assets/{}:restore", ASSETS_API, asset_id), json!({}), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
+
+/// Fetches an operation returned by [create_asset] or [update_asset] from its id
+pub async fn asset_operation_from_id(operation_id: &str, credential: Option<Credential>) -> RobloxResult<Operation<CloudAsset>> {
+  api_helper::cloud_get(format!("{}/operations/{}", ASSETS_API, operation_id), credential.clone())
+    .await
+    .map_async(api_helper::deserialize_body::<Operation<CloudAsset>>)
+    .await?
+    .map(|operation| operation.with_context(ASSETS_API, credential))
+}
@@ -0,0 +1,123 @@
+use serde_json::json;
+
+use crate::{
+  models::{CloudGroup, CloudGroupShout, CloudJoinRequest, GroupMembership, GroupRole},
+  util::{
+    api_helper,
+    paging::{identity_mapper, CloudPageIterator},
+    responses::CloudGroupRoleResponse,
+    Credential, ResultExtensions, RobloxResult,
+  },
+};
+
+const GROUPS_API: &str = "https://apis.roblox.com/cloud/v2/groups";
+
+/// Narrows down the memberships listed by [group_memberships]
+pub enum MembershipFilter {
+  User(i64),
+  Users(Vec<i64>),
+  Role(i64),
+  /// A filter expression as documented by Roblox, such as `user == 'users/156'`
+  Raw(String),
+}
+
+impl MembershipFilter {
+  fn serialize(&self, group_id: i64) -> String {
+    match self {
+      MembershipFilter::User(user_id) => format!("user == 'users/{}'", user_id),
+      MembershipFilter::Users(user_ids) => format!(
+        "user in [{}]",
+        user_ids
+          .iter()
+          .map(|user_id| format!("'users/{}'", user_id))
+          .collect::<Vec<_>>()
+          .join(", ")
+      ),
+      MembershipFilter::Role(role_id) => format!("role == 'groups/{}/roles/{}'", group_id, role_id),
+      MembershipFilter::Raw(filter) => filter.clone(),
+    }
+  }
+}
+
+pub async fn group_from_id(group_id: i64, credential: Option<Credential>) -> RobloxResult<CloudGroup> {
+  api_helper::cloud_get(format!("{}/{}", GROUPS_API, group_id), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
+
+pub fn group_memberships(
+  group_id: i64,
+  filter: Option<MembershipFilter>,
+  credential: Option<Credential>,
+) -> CloudPageIterator<GroupMembership, GroupMembership> {
+  CloudPageIterator::new(
+    format!("{}/{}/memberships", GROUPS_API, group_id),
+    "groupMemberships",
+    identity_mapper,
+    credential,
+  )
+  .filter(filter.map(|filter| filter.serialize(group_id)))
+}
+
+pub async fn update_group_membership(group_id: i64, user_id: i64, role_id: i64, credential: Option<Credential>) -> RobloxResult<GroupMembership> {
+  api_helper::cloud_patch(
+    format!("{}/{}/memberships/{}", GROUPS_API, group_id, user_id),
+    json!({ "role": format!("groups/{}/roles/{}", group_id, role_id) }),
+    credential,
+  )
+  .await
+  .map_async(api_helper::deserialize_body)
+  .await?
+}
+
+pub fn group_roles(group_id: i64, credential: Option<Credential>) -> CloudPageIterator<CloudGroupRoleResponse, GroupRole> {
+  CloudPageIterator::new(
+    format!("{}/{}/roles", GROUPS_API, group_id),
+    "groupRoles",
+    |role| GroupRole {
+      id: role.id,
+      name: role.display_name.clone(),
+      description: role.description.clone(),
+      rank: role.rank,
+      member_count: role.member_count,
+    },
+    credential,
+  )
+}
+
+pub fn group_join_requests(group_id: i64, credential: Option<Credential>) -> CloudPageIterator<CloudJoinRequest, CloudJoinRequest> {
+  CloudPageIterator::new(
+    format!("{}/{}/join-requests", GROUPS_API, group_id),
+    "groupJoinRequests",
+    identity_mapper,
+    credential,
+  )
+}
+
+pub async fn accept_group_join_request(group_id: i64, user_id: i64, credential: Option<Credential>) -> RobloxResult<()> {
+  api_helper::cloud_post(
+    format!("{}/{}/join-requests/{}:accept", GROUPS_API, group_id, user_id),
+    json!({}),
+    credential,
+  )
+  .await
+  .map(|_| ())
+}
+
+pub async fn decline_group_join_request(group_id: i64, user_id: i64, credential: Option<Credential>) -> RobloxResult<()> {
+  api_helper::cloud_post(
+    format!("{}/{}/join-requests/{}:decline", GROUPS_API, group_id, user_id),
+    json!({}),
+    credential,
+  )
+  .await
+  .map(|_| ())
+}
+
+pub async fn group_shout(group_id: i64, credential: Option<Credential>) -> RobloxResult<CloudGroupShout> {
+  api_helper::cloud_get(format!("{}/{}/shout", GROUPS_API, group_id), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
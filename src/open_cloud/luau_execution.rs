@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+  models::{LuauExecutionTask, LuauExecutionTaskLog},
+  util::{
+    api_helper,
+    paging::{identity_mapper, CloudPageIterator},
+    Credential, ResultExtensions, RobloxResult,
+  },
+};
+
+/// Queues a script to run against a version of a place, or against its latest version if `version_number` is `None`
+///
+/// The returned task is not done yet, use [LuauExecutionTask::wait] to get its output.
+pub async fn create_luau_execution_task(
+  universe_id: i64,
+  place_id: i64,
+  version_number: Option<i64>,
+  script: &str,
+  timeout: Option<Duration>,
+  credential: Option<Credential>,
+) -> RobloxResult<LuauExecutionTask> {
+  let place_path = match version_number {
+    Some(version_number) => format!("universes/{}/places/{}/versions/{}", universe_id, place_id, version_number),
+    None => format!("universes/{}/places/{}", universe_id, place_id),
+  };
+
+  let mut task = Map::new();
+  task.insert("script".to_owned(), json!(script));
+  if let Some(timeout) = timeout {
+    task.insert("timeout".to_owned(), json!(format!("{}s", timeout.as_secs())));
+  }
+
+  api_helper::cloud_post(
+    format!("https://apis.roblox.com/cloud/v2/{}/luau-execution-session-tasks", place_path),
+    Value::Object(task),
+    credential.clone(),
+  )
+  .await
+  .map_async(api_helper::deserialize_body::<LuauExecutionTask>)
+  .await?
+  .map(|task| task.with_credential(credential))
+}
+
+/// Fetches a task from its path, as found in [LuauExecutionTask::path]
+pub async fn luau_execution_task(path: &str, credential: Option<Credential>) -> RobloxResult<LuauExecutionTask> {
+  api_helper::cloud_get(format!("https://apis.roblox.com/cloud/v2/{}", path), credential.clone())
+    .await
+    .map_async(api_helper::deserialize_body::<LuauExecutionTask>)
+    .await?
+    .map(|task| task.with_credential(credential))
+}
+
+/// Lists the logs of a task from its path, as found in [LuauExecutionTask::path]
+pub fn luau_execution_task_logs(path: &str, credential: Option<Credential>) -> CloudPageIterator<LuauExecutionTaskLog, LuauExecutionTaskLog> {
+  CloudPageIterator::new(
+    format!("https://apis.roblox.com/cloud/v2/{}/logs?view=STRUCTURED", path),
+    "luauExecutionSessionTaskLogs",
+    identity_mapper,
+    credential,
+  )
+}
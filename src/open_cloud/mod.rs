@@ -0,0 +1,30 @@
+//! Functions for the Open Cloud API
+//!
+//! Open Cloud endpoints are authenticated with an API key or an OAuth 2.0
+//! access token instead of the .ROBLOSECURITY cookie. The credential can be
+//! set once through [set_api_key](crate::set_api_key):
+//!
+//! ```rust
+//! oxid_roblox::set_api_key(&env::var("API_KEY").unwrap());
+//! ```
+//!
+//! Or passed to each function as a [Credential](crate::util::Credential),
+//! which takes priority over the one that was set.
+
+mod assets;
+mod groups;
+mod luau_execution;
+mod notifications;
+mod places;
+mod subscriptions;
+mod user_restrictions;
+mod users;
+
+pub use assets::*;
+pub use groups::*;
+pub use luau_execution::*;
+pub use notifications::*;
+pub use places::*;
+pub use subscriptions::*;
+pub use user_restrictions::*;
+pub use users::*;
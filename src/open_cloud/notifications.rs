@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::util::{api_helper, Credential, ResultExtensions, RobloxResult};
+
+/// A value substituted into the notification string
+#[derive(Debug, Clone)]
+pub enum NotificationParameter {
+  String(String),
+  Int64(i64),
+}
+
+impl NotificationParameter {
+  fn serialize(&self) -> Value {
+    match self {
+      NotificationParameter::String(value) => json!({ "stringValue": value }),
+      NotificationParameter::Int64(value) => json!({ "int64Value": value }),
+    }
+  }
+}
+
+/// The content of an experience notification
+#[derive(Debug, Clone, Default)]
+pub struct NotificationPayload {
+  /// The id of the notification string created on the Creator Dashboard
+  pub message_id: String,
+  pub parameters: HashMap<String, NotificationParameter>,
+  /// Data passed to the experience when the user joins from the notification. Without it the notification has no join button
+  pub launch_data: Option<String>,
+  /// The category used to group notifications in analytics
+  pub analytics_category: Option<String>,
+}
+
+impl NotificationPayload {
+  pub fn new(message_id: &str) -> Self {
+    Self {
+      message_id: message_id.to_owned(),
+      ..Default::default()
+    }
+  }
+
+  fn serialize(&self) -> Value {
+    let parameters = self
+      .parameters
+      .iter()
+      .map(|(name, parameter)| (name.clone(), parameter.serialize()))
+      .collect::<Map<String, Value>>();
+
+    let mut payload = json!({
+        "type": "MOMENT",
+        "messageId": self.message_id,
+        "parameters": parameters
+    });
+
+    if let Some(launch_data) = &self.launch_data {
+      payload["joinExperience"] = json!({ "launchData": launch_data });
+    }
+    if let Some(category) = &self.analytics_category {
+      payload["analyticsData"] = json!({ "category": category });
+    }
+
+    payload
+  }
+}
+
+#[derive(Deserialize)]
+struct NotificationResponse {
+  id: String,
+}
+
+/// Sends an experience notification to a user on behalf of a universe and returns the id of the notification
+pub async fn send_user_notification(
+  user_id: i64,
+  universe_id: i64,
+  payload: NotificationPayload,
+  credential: Option<Credential>,
+) -> RobloxResult<String> {
+  api_helper::cloud_post(
+    format!("https://apis.roblox.com/cloud/v2/users/{}/notifications", user_id),
+    json!({
+        "source": { "universe": format!("universes/{}", universe_id) },
+        "payload": payload.serialize()
+    }),
+    credential,
+  )
+  .await
+  .map_async(api_helper::deserialize_body::<NotificationResponse>)
+  .await?
+  .map(|data| data.id)
+}
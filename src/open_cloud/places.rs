@@ -0,0 +1,77 @@
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::util::{
+  api_helper::{self, CloudBody},
+  Credential, OxidError, ResultExtensions, RobloxResult,
+};
+
+/// The largest place file accepted by the place publishing API
+pub const PLACE_FILE_SIZE_LIMIT: usize = 100 * 1024 * 1024;
+
+pub enum PlaceVersionType {
+  /// The version is saved but players keep joining the currently published version
+  Saved,
+  /// The version is saved and players join it from now on
+  Published,
+}
+
+impl PlaceVersionType {
+  fn serialize(&self) -> String {
+    match self {
+      PlaceVersionType::Saved => "Saved",
+      PlaceVersionType::Published => "Published",
+    }
+    .to_owned()
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PlaceVersionResponse {
+  version_number: i64,
+}
+
+/// Binary place files (.rbxl) start with `<roblox!`, while XML place files (.rbxlx) start with `<roblox `
+/// or an XML declaration
+fn place_content_type(place_file: &[u8]) -> &'static str {
+  if place_file.starts_with(b"<roblox!") {
+    "application/octet-stream"
+  } else {
+    "application/xml"
+  }
+}
+
+/// Uploads a .rbxl or .rbxlx file as a new version of the place and returns the new version number
+///
+/// Returns [OxidError::PayloadTooLarge] without sending a request if the file is larger than [PLACE_FILE_SIZE_LIMIT].
+pub async fn publish_place(
+  universe_id: i64,
+  place_id: i64,
+  place_file: Vec<u8>,
+  version_type: PlaceVersionType,
+  credential: Option<Credential>,
+) -> RobloxResult<i64> {
+  if place_file.len() > PLACE_FILE_SIZE_LIMIT {
+    return Err(OxidError::PayloadTooLarge);
+  }
+
+  api_helper::cloud_request(
+    Method::POST,
+    format!(
+      "https://apis.roblox.com/universes/v1/{}/places/{}/versions?versionType={}",
+      universe_id,
+      place_id,
+      version_type.serialize()
+    ),
+    CloudBody::Raw {
+      content_type: place_content_type(&place_file).to_owned(),
+      bytes: place_file,
+    },
+    credential,
+  )
+  .await
+  .map_async(api_helper::deserialize_body::<PlaceVersionResponse>)
+  .await?
+  .map(|data| data.version_number)
+}
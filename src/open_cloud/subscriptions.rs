@@ -0,0 +1,18 @@
+use crate::{
+  models::Subscription,
+  util::{api_helper, Credential, ResultExtensions, RobloxResult},
+};
+
+/// Fetches the subscription of a user to a subscription product, e.g. `EXP-1234567890`
+pub async fn subscription(universe_id: i64, product_id: &str, user_id: i64, credential: Option<Credential>) -> RobloxResult<Subscription> {
+  api_helper::cloud_get(
+    format!(
+      "https://apis.roblox.com/cloud/v2/universes/{}/subscription-products/{}/subscriptions/{}?view=FULL",
+      universe_id, product_id, user_id
+    ),
+    credential,
+  )
+  .await
+  .map_async(api_helper::deserialize_body)
+  .await?
+}
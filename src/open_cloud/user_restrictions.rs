@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+  models::{UserRestriction, UserRestrictionLog},
+  util::{
+    api_helper,
+    paging::{identity_mapper, CloudPageIterator},
+    Credential, ResultExtensions, RobloxResult,
+  },
+};
+
+/// Changes to the game join restriction (ban) of a user. Fields that are `None` are left unchanged
+#[derive(Debug, Clone, Default)]
+pub struct RestrictionUpdate {
+  pub active: Option<bool>,
+  /// `None` keeps the current duration, `Some(None)` clears it. A restriction without a duration is permanent
+  pub duration: Option<Option<Duration>>,
+  /// The reason shown to moderators
+  pub private_reason: Option<String>,
+  /// The reason shown to the restricted user
+  pub display_reason: Option<String>,
+  pub exclude_alt_accounts: Option<bool>,
+}
+
+impl RestrictionUpdate {
+  /// Bans the user, permanently if `duration` is `None`
+  pub fn ban(duration: Option<Duration>, display_reason: &str, private_reason: &str) -> Self {
+    Self {
+      active: Some(true),
+      duration: Some(duration),
+      private_reason: Some(private_reason.to_owned()),
+      display_reason: Some(display_reason.to_owned()),
+      exclude_alt_accounts: None,
+    }
+  }
+
+  pub fn unban() -> Self {
+    Self {
+      active: Some(false),
+      ..Default::default()
+    }
+  }
+
+  // Returns the request body and the update mask listing the fields that are set. A cleared duration is in the
+  // mask but not in the body
+  fn serialize(&self) -> (Value, String) {
+    let mut restriction = Map::new();
+    let mut mask = Vec::new();
+
+    if let Some(active) = self.active {
+      restriction.insert("active".to_owned(), json!(active));
+      mask.push("active");
+    }
+    if let Some(duration) = self.duration {
+      if let Some(duration) = duration {
+        restriction.insert("duration".to_owned(), json!(format!("{}s", duration.as_secs())));
+      }
+      mask.push("duration");
+    }
+    if let Some(private_reason) = &self.private_reason {
+      restriction.insert("privateReason".to_owned(), json!(private_reason));
+      mask.push("privateReason");
+    }
+    if let Some(display_reason) = &self.display_reason {
+      restriction.insert("displayReason".to_owned(), json!(display_reason));
+      mask.push("displayReason");
+    }
+    if let Some(exclude_alt_accounts) = self.exclude_alt_accounts {
+      restriction.insert("excludeAltAccounts".to_owned(), json!(exclude_alt_accounts));
+      mask.push("excludeAltAccounts");
+    }
+
+    let update_mask = mask
+      .iter()
+      .map(|field| format!("gameJoinRestriction.{}", field))
+      .collect::<Vec<_>>()
+      .join(",");
+
+    (json!({ "gameJoinRestriction": restriction }), update_mask)
+  }
+}
+
+// Restrictions can be scoped to a single place of the universe
+fn user_restrictions_url(universe_id: i64, place_id: Option<i64>) -> String {
+  match place_id {
+    Some(place_id) => format!(
+      "https://apis.roblox.com/cloud/v2/universes/{}/places/{}/user-restrictions",
+      universe_id, place_id
+    ),
+    None => format!("https://apis.roblox.com/cloud/v2/universes/{}/user-restrictions", universe_id),
+  }
+}
+
+/// Lists the restrictions of a universe, or of one of its places if `place_id` is given
+pub fn user_restrictions(
+  universe_id: i64,
+  place_id: Option<i64>,
+  credential: Option<Credential>,
+) -> CloudPageIterator<UserRestriction, UserRestriction> {
+  CloudPageIterator::new(
+    user_restrictions_url(universe_id, place_id),
+    "userRestrictions",
+    identity_mapper,
+    credential,
+  )
+}
+
+pub async fn user_restriction(
+  universe_id: i64,
+  place_id: Option<i64>,
+  user_id: i64,
+  credential: Option<Credential>,
+) -> RobloxResult<UserRestriction> {
+  api_helper::cloud_get(format!("{}/{}", user_restrictions_url(universe_id, place_id), user_id), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
+
+pub async fn update_user_restriction(
+  universe_id: i64,
+  place_id: Option<i64>,
+  user_id: i64,
+  update: RestrictionUpdate,
+  credential: Option<Credential>,
+) -> RobloxResult<UserRestriction> {
+  let (body, update_mask) = update.serialize();
+
+  api_helper::cloud_patch(
+    format!("{}/{}?updateMask={}", user_restrictions_url(universe_id, place_id), user_id, update_mask),
+    body,
+    credential,
+  )
+  .await
+  .map_async(api_helper::deserialize_body)
+  .await?
+}
+
+/// Lists the changes made to restrictions of a universe, optionally only the ones of a user and/or place
+pub fn user_restriction_logs(
+  universe_id: i64,
+  user_id: Option<i64>,
+  place_id: Option<i64>,
+  credential: Option<Credential>,
+) -> CloudPageIterator<UserRestrictionLog, UserRestrictionLog> {
+  let mut filters = Vec::new();
+  if let Some(user_id) = user_id {
+    filters.push(format!("user == 'users/{}'", user_id));
+  }
+  if let Some(place_id) = place_id {
+    filters.push(format!("place == 'places/{}'", place_id));
+  }
+
+  CloudPageIterator::new(
+    format!("https://apis.roblox.com/cloud/v2/universes/{}/user-restrictions:listLogs", universe_id),
+    "logs",
+    identity_mapper,
+    credential,
+  )
+  .filter((!filters.is_empty()).then(|| filters.join(" && ")))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn permanent_ban_clears_duration() {
+    let (body, update_mask) = RestrictionUpdate::ban(None, "Cheating", "Flying exploit").serialize();
+
+    assert!(update_mask.split(',').any(|field| field == "gameJoinRestriction.duration"));
+    assert!(body["gameJoinRestriction"].get("duration").is_none());
+
+    let (body, update_mask) = RestrictionUpdate::ban(Some(Duration::from_secs(3600)), "Cheating", "Flying exploit").serialize();
+
+    assert!(update_mask.split(',').any(|field| field == "gameJoinRestriction.duration"));
+    assert_eq!(body["gameJoinRestriction"]["duration"], "3600s");
+  }
+
+  #[test]
+  fn unban_keeps_duration() {
+    let (body, update_mask) = RestrictionUpdate::unban().serialize();
+
+    assert_eq!(update_mask, "gameJoinRestriction.active");
+    assert_eq!(body, json!({ "gameJoinRestriction": { "active": false } }));
+  }
+}
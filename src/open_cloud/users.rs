@@ -0,0 +1,81 @@
+use crate::{
+  models::{CloudUser, InventoryItem},
+  util::{api_helper, paging::CloudPageIterator, responses::InventoryItemResponse, Credential, ResultExtensions, RobloxResult},
+};
+
+/// Narrows down the items listed by [inventory_items]. An empty filter lists every item
+#[derive(Debug, Clone, Default)]
+pub struct InventoryFilter {
+  pub asset_ids: Vec<i64>,
+  /// Asset types as named by Open Cloud, e.g. `HAT` or `CLASSIC_SHIRT`
+  pub asset_types: Vec<String>,
+  pub badge_ids: Vec<i64>,
+  pub game_pass_ids: Vec<i64>,
+  pub private_server_ids: Vec<i64>,
+  /// Includes every badge
+  pub badges: bool,
+  /// Includes every gamepass
+  pub game_passes: bool,
+  /// Includes every private server
+  pub private_servers: bool,
+  pub only_collectibles: bool,
+}
+
+impl InventoryFilter {
+  // Open Cloud expects semicolon separated fields, e.g. "badgeIds=1,2;gamePasses=true"
+  fn serialize(&self) -> Option<String> {
+    let join_ids = |ids: &Vec<i64>| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
+    let mut fields = Vec::new();
+
+    if !self.asset_ids.is_empty() {
+      fields.push(format!("assetIds={}", join_ids(&self.asset_ids)));
+    }
+    if !self.asset_types.is_empty() {
+      fields.push(format!("inventoryItemAssetTypes={}", self.asset_types.join(",")));
+    }
+    if !self.badge_ids.is_empty() {
+      fields.push(format!("badgeIds={}", join_ids(&self.badge_ids)));
+    }
+    if !self.game_pass_ids.is_empty() {
+      fields.push(format!("gamePassIds={}", join_ids(&self.game_pass_ids)));
+    }
+    if !self.private_server_ids.is_empty() {
+      fields.push(format!("privateServerIds={}", join_ids(&self.private_server_ids)));
+    }
+    if self.badges {
+      fields.push("badges=true".to_owned());
+    }
+    if self.game_passes {
+      fields.push("gamePasses=true".to_owned());
+    }
+    if self.private_servers {
+      fields.push("privateServers=true".to_owned());
+    }
+    if self.only_collectibles {
+      fields.push("onlyCollectibles=true".to_owned());
+    }
+
+    (!fields.is_empty()).then(|| fields.join(";"))
+  }
+}
+
+pub async fn user_from_id(user_id: i64, credential: Option<Credential>) -> RobloxResult<CloudUser> {
+  api_helper::cloud_get(format!("https://apis.roblox.com/cloud/v2/users/{}", user_id), credential)
+    .await
+    .map_async(api_helper::deserialize_body)
+    .await?
+}
+
+pub fn inventory_items(
+  user_id: i64,
+  filter: InventoryFilter,
+  credential: Option<Credential>,
+) -> CloudPageIterator<InventoryItemResponse, InventoryItem> {
+  CloudPageIterator::new(
+    format!("https://apis.roblox.com/cloud/v2/users/{}/inventory-items", user_id),
+    "inventoryItems",
+    InventoryItemResponse::to_item,
+    credential,
+  )
+  .filter(filter.serialize())
+}
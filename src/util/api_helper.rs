@@ -1,13 +1,13 @@
 use std::sync::{Arc, Mutex};
 
 use lazy_static::lazy_static;
-use reqwest::{header::HeaderMap, Client, Method, Response, StatusCode};
+use reqwest::{header::HeaderMap, Client, Method, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use crate::util::OxidError;
+use crate::{oauth::TokenRefresher, util::OxidError};
 
-use super::{get_api_errors_from_response, RobloxResult};
+use super::{get_api_errors_from_response, get_open_cloud_error_from_response, multipart::MultipartForm, Credential, RobloxResult};
 
 lazy_static! {
   static ref HTTP_CLIENT: Client = Client::new();
@@ -17,6 +17,8 @@ lazy_static! {
     headers.insert("Referer", "www.roblox.com".parse().unwrap());
     headers
   }));
+  static ref CREDENTIAL: Arc<Mutex<Option<Credential>>> = Arc::new(Mutex::new(None));
+  static ref TOKEN_REFRESHER: Arc<Mutex<Option<Arc<TokenRefresher>>>> = Arc::new(Mutex::new(None));
 }
 
 pub(crate) fn set_roblosecurity(roblosecurity: &str) {
@@ -27,13 +29,39 @@ pub(crate) fn set_roblosecurity(roblosecurity: &str) {
     .insert("Cookie", format!(".ROBLOSECURITY={};", roblosecurity).parse().unwrap());
 }
 
+pub(crate) fn set_credential(credential: Credential) {
+  *CREDENTIAL.clone().lock().unwrap() = Some(credential);
+}
+
+pub(crate) fn set_token_refresher(token_refresher: TokenRefresher) {
+  *TOKEN_REFRESHER.clone().lock().unwrap() = Some(Arc::new(token_refresher));
+}
+
+/// The body of a request. Legacy endpoints only take json, apart from uploads
+#[derive(Clone)]
+pub(crate) enum CloudBody {
+  Empty,
+  Json(Value),
+  Form(Vec<(String, String)>),
+  Raw { content_type: String, bytes: Vec<u8> },
+}
+
 /// parses the json of the response into the expected response structure
 /// if it fails to parse it returns OxidError::Http
 pub(crate) async fn deserialize_body<T: DeserializeOwned>(response: Response) -> RobloxResult<T> {
-  response.json::<T>().await.map_err(|e| OxidError::Http(e))
+  response.json::<T>().await.map_err(OxidError::Http)
 }
 
-async fn request(verb: Method, url: String, body: Option<Value>, roblosecurity: Option<String>) -> RobloxResult<Response> {
+fn with_body(request: RequestBuilder, body: CloudBody) -> RequestBuilder {
+  match body {
+    CloudBody::Empty => request,
+    CloudBody::Json(json) => request.json(&json),
+    CloudBody::Form(form) => request.form(&form),
+    CloudBody::Raw { content_type, bytes } => request.header("Content-Type", content_type).body(bytes),
+  }
+}
+
+async fn request(verb: Method, url: String, body: CloudBody, roblosecurity: Option<String>) -> RobloxResult<Response> {
   let arc_ref = HEADERS.clone();
   let headers_clone = {
     let mut headers = arc_ref.lock().unwrap();
@@ -46,17 +74,12 @@ async fn request(verb: Method, url: String, body: Option<Value>, roblosecurity:
     headers.clone()
   };
 
-  let response = HTTP_CLIENT
-    .request(verb.clone(), url.clone())
-    .headers(headers_clone)
-    .json(&body)
+  let response = with_body(HTTP_CLIENT.request(verb.clone(), url.clone()).headers(headers_clone), body.clone())
     .send()
     .await
     .unwrap();
 
   match response.status() {
-    StatusCode::OK => Ok(response),
-    StatusCode::UNAUTHORIZED => Err(OxidError::Unauthorized),
     StatusCode::FORBIDDEN => {
       // Get the x-csrf-token here because get_api_errors_from_response consumes the response
       let x_csrf_token = response.headers().get("x-csrf-token").cloned();
@@ -71,12 +94,26 @@ async fn request(verb: Method, url: String, body: Option<Value>, roblosecurity:
           headers.clone()
         };
 
-        Ok(HTTP_CLIENT.request(verb, url).headers(headers_clone).json(&body).send().await.unwrap())
+        let response = with_body(HTTP_CLIENT.request(verb, url).headers(headers_clone), body)
+          .send()
+          .await
+          .unwrap();
+
+        // The retried request can fail like any other, so it goes through the same status handling
+        check_status(response).await
       } else {
         // regular forbidden message thats not from csrf
         Err(OxidError::Api(errors))
       }
     }
+    _ => check_status(response).await,
+  }
+}
+
+async fn check_status(response: Response) -> RobloxResult<Response> {
+  match response.status() {
+    StatusCode::OK => Ok(response),
+    StatusCode::UNAUTHORIZED => Err(OxidError::Unauthorized),
 
     // Any other error, we parse the errors array and format it as OxidError::Roblox
     _ => {
@@ -87,17 +124,101 @@ async fn request(verb: Method, url: String, body: Option<Value>, roblosecurity:
 }
 
 pub async fn get(url: String, roblosecurity: Option<String>) -> RobloxResult<Response> {
-  request(Method::GET, url, None, roblosecurity).await
+  request(Method::GET, url, CloudBody::Json(Value::Null), roblosecurity).await
 }
 
 pub async fn delete(url: String, roblosecurity: Option<String>) -> RobloxResult<Response> {
-  request(Method::DELETE, url, None, roblosecurity).await
+  request(Method::DELETE, url, CloudBody::Json(Value::Null), roblosecurity).await
+}
+
+pub async fn delete_with_body(url: String, body: Value, roblosecurity: Option<String>) -> RobloxResult<Response> {
+  request(Method::DELETE, url, CloudBody::Json(body), roblosecurity).await
 }
 
 pub async fn post(url: String, body: Value, roblosecurity: Option<String>) -> RobloxResult<Response> {
-  request(Method::POST, url, Some(body), roblosecurity).await
+  request(Method::POST, url, CloudBody::Json(body), roblosecurity).await
 }
 
 pub async fn patch(url: String, body: Value, roblosecurity: Option<String>) -> RobloxResult<Response> {
-  request(Method::PATCH, url, Some(body), roblosecurity).await
+  request(Method::PATCH, url, CloudBody::Json(body), roblosecurity).await
+}
+
+/// sends a multipart form, such as an image upload, to a legacy endpoint
+pub(crate) async fn patch_multipart(url: String, form: MultipartForm, roblosecurity: Option<String>) -> RobloxResult<Response> {
+  let (content_type, bytes) = form.build();
+  request(Method::PATCH, url, CloudBody::Raw { content_type, bytes }, roblosecurity).await
+}
+
+/// sends a request to an Open Cloud endpoint, authenticated with the given credential. If none is given,
+/// the token store set through [set_token_store](crate::set_token_store) is used, and otherwise the
+/// credential set through [set_api_key](crate::set_api_key)
+pub(crate) async fn cloud_request(verb: Method, url: String, body: CloudBody, credential: Option<Credential>) -> RobloxResult<Response> {
+  if credential.is_some() {
+    return send_cloud_request(verb, url, body, credential).await;
+  }
+
+  let token_refresher = TOKEN_REFRESHER.clone().lock().unwrap().clone();
+
+  match token_refresher {
+    Some(token_refresher) => {
+      let credential = token_refresher.credential(None).await?;
+
+      match send_cloud_request(verb.clone(), url.clone(), body.clone(), Some(credential.clone())).await {
+        // The token may have been revoked or expired early, so refresh it and retry once
+        Err(OxidError::Unauthorized) => {
+          let rejected_token = match &credential {
+            Credential::Bearer(token) => Some(token.as_str()),
+            Credential::ApiKey(_) => None,
+          };
+          let credential = token_refresher.credential(rejected_token).await?;
+
+          send_cloud_request(verb, url, body, Some(credential)).await
+        }
+        result => result,
+      }
+    }
+    None => {
+      let credential = CREDENTIAL.clone().lock().unwrap().clone();
+      send_cloud_request(verb, url, body, credential).await
+    }
+  }
+}
+
+/// sends a request to an Open Cloud endpoint with exactly the given credential, without falling back to
+/// the one that was set. Used by endpoints such as the OAuth 2.0 token endpoint that authenticate through the body
+pub(crate) async fn send_cloud_request(verb: Method, url: String, body: CloudBody, credential: Option<Credential>) -> RobloxResult<Response> {
+  let mut request = HTTP_CLIENT.request(verb, url).header("User-Agent", "Roblox/WinInet");
+
+  request = match credential {
+    Some(Credential::ApiKey(key)) => request.header("x-api-key", key),
+    Some(Credential::Bearer(token)) => request.bearer_auth(token),
+    None => request,
+  };
+
+  request = with_body(request, body);
+
+  let response = request.send().await.map_err(OxidError::Http)?;
+
+  match response.status() {
+    status if status.is_success() => Ok(response),
+    StatusCode::UNAUTHORIZED => Err(OxidError::Unauthorized),
+    StatusCode::PAYLOAD_TOO_LARGE => Err(OxidError::PayloadTooLarge),
+    _ => Err(OxidError::OpenCloud(get_open_cloud_error_from_response(response).await?)),
+  }
+}
+
+pub async fn cloud_get(url: String, credential: Option<Credential>) -> RobloxResult<Response> {
+  cloud_request(Method::GET, url, CloudBody::Empty, credential).await
+}
+
+pub async fn cloud_delete(url: String, credential: Option<Credential>) -> RobloxResult<Response> {
+  cloud_request(Method::DELETE, url, CloudBody::Empty, credential).await
+}
+
+pub async fn cloud_post(url: String, body: Value, credential: Option<Credential>) -> RobloxResult<Response> {
+  cloud_request(Method::POST, url, CloudBody::Json(body), credential).await
+}
+
+pub async fn cloud_patch(url: String, body: Value, credential: Option<Credential>) -> RobloxResult<Response> {
+  cloud_request(Method::PATCH, url, CloudBody::Json(body), credential).await
 }
@@ -0,0 +1,13 @@
+/// A credential used to authenticate requests sent to Open Cloud endpoints
+///
+/// Open Cloud does not accept the .ROBLOSECURITY cookie. Instead, requests are
+/// authenticated either with an API key created on the Creator Dashboard or an
+/// OAuth 2.0 access token.
+#[derive(Debug, Clone)]
+pub enum Credential {
+  /// An Open Cloud API key, sent through the `x-api-key` header
+  ApiKey(String),
+
+  /// An OAuth 2.0 access token, sent through the `Authorization` header
+  Bearer(String),
+}
@@ -11,6 +11,31 @@ pub struct ApiError {
   pub user_facing_message: Option<String>,
 }
 
+/// An error returned from an Open Cloud endpoint
+///
+/// Open Cloud does not use the `errors` array of the legacy web API. Version 1
+/// endpoints name the code `error` while version 2 endpoints name it `code`.
+/// OAuth 2.0 endpoints follow RFC 6749, with an `error` and `error_description`.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenCloudError {
+  /// The HTTP status code of the response
+  #[serde(skip)]
+  pub status: u16,
+  #[serde(alias = "error", default)]
+  pub code: String,
+  #[serde(alias = "error_description", default)]
+  pub message: String,
+}
+
+/// The error of a long-running operation that finished unsuccessfully
+#[derive(Deserialize, Debug, Clone)]
+pub struct OperationError {
+  pub code: i32,
+  #[serde(default)]
+  pub message: String,
+}
+
 // I have yet to see a request with multiple errors Im leaving it because the csrf refetch code uses it and i cant bother
 type ApiErrors = Vec<ApiError>;
 
@@ -19,12 +44,30 @@ pub enum OxidError {
   /// Typically 400 errors returned from roblox
   Api(ApiErrors),
 
-  ///
+  /// Errors from sending a request or parsing its body
   Http(reqwest::Error),
 
   /// A bad cookie is used to perform a request
   Unauthorized,
 
+  /// Roblox requires a captcha to be solved before the action can be performed, which can't be done from this crate
+  CaptchaRequired,
+
+  /// The user is already in the maximum number of groups
+  TooManyGroups,
+
+  /// The user already requested to join the group, and the request is awaiting approval
+  JoinRequestPending,
+
+  /// Errors returned from Open Cloud endpoints
+  OpenCloud(OpenCloudError),
+
+  /// The uploaded file is larger than what the endpoint accepts
+  PayloadTooLarge,
+
+  /// A long-running Open Cloud operation finished with an error
+  Operation(OperationError),
+
   /// errors from processing of data fetched from the API
   Validation(String),
 }
@@ -35,6 +78,12 @@ impl std::fmt::Display for OxidError {
       OxidError::Api(errors) => write!(f, "OxidRoblox API Error: {:?}", errors),
       OxidError::Http(e) => write!(f, "OxidRoblox HTTP Error: {}", e),
       OxidError::Unauthorized => write!(f, "OxidRoblox Error: Invalid or unauthorized cookie when using an authenticated request"),
+      OxidError::CaptchaRequired => write!(f, "OxidRoblox Error: A captcha must be solved to perform this action"),
+      OxidError::TooManyGroups => write!(f, "OxidRoblox Error: The user is already in the maximum number of groups"),
+      OxidError::JoinRequestPending => write!(f, "OxidRoblox Error: The request to join the group is awaiting approval"),
+      OxidError::OpenCloud(error) => write!(f, "OxidRoblox Open Cloud Error ({}): {} {}", error.status, error.code, error.message),
+      OxidError::PayloadTooLarge => write!(f, "OxidRoblox Error: The uploaded file is too large"),
+      OxidError::Operation(error) => write!(f, "OxidRoblox Operation Error ({}): {}", error.code, error.message),
       OxidError::Validation(msg) => write!(f, "OxidRoblox Rrror: {}", msg),
     }
   }
@@ -48,4 +97,20 @@ pub(crate) async fn get_api_errors_from_response(response: Response) -> RobloxRe
   Ok(api_helper::deserialize_body::<ErrorResponse>(response).await?.errors)
 }
 
+/// parses the error json of an Open Cloud response. Some failures (such as ones from the gateway) don't
+/// return json, in which case the body is used as the message
+pub(crate) async fn get_open_cloud_error_from_response(response: Response) -> RobloxResult<OpenCloudError> {
+  let status = response.status();
+  let body = response.text().await.map_err(OxidError::Http)?;
+
+  let mut error = serde_json::from_str::<OpenCloudError>(&body).unwrap_or_else(|_| OpenCloudError {
+    status: 0,
+    code: status.canonical_reason().unwrap_or_default().to_owned(),
+    message: body,
+  });
+  error.status = status.as_u16();
+
+  Ok(error)
+}
+
 pub type RobloxResult<T> = Result<T, OxidError>;
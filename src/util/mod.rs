@@ -1,3 +1,6 @@
+mod credential;
+pub use credential::Credential;
+
 mod errors;
 pub use errors::*;
 
@@ -5,6 +8,8 @@ mod result_extensions;
 pub(crate) use result_extensions::ResultExtensions;
 
 pub mod api_helper;
+pub(crate) mod multipart;
 pub mod paging;
 pub(crate) mod parsers;
+pub mod polling;
 pub(crate) mod responses;
@@ -0,0 +1,46 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// A minimal multipart/form-data encoder. The body is kept as raw bytes so that it can be
+// cloned and resent, which reqwest's own multipart form does not allow.
+pub(crate) struct MultipartForm {
+  boundary: String,
+  body: Vec<u8>,
+}
+
+impl MultipartForm {
+  pub(crate) fn new() -> Self {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or_default();
+
+    Self {
+      boundary: format!("oxid-roblox-boundary-{:x}", nanos),
+      body: Vec::new(),
+    }
+  }
+
+  pub(crate) fn text(self, name: &str, value: &str) -> Self {
+    self.part(format!("Content-Disposition: form-data; name=\"{}\"\r\n", name), value.as_bytes())
+  }
+
+  pub(crate) fn file(self, name: &str, file_name: &str, content_type: &str, content: &[u8]) -> Self {
+    self.part(
+      format!(
+        "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n",
+        name, file_name, content_type
+      ),
+      content,
+    )
+  }
+
+  fn part(mut self, headers: String, content: &[u8]) -> Self {
+    self.body.extend_from_slice(format!("--{}\r\n{}\r\n", self.boundary, headers).as_bytes());
+    self.body.extend_from_slice(content);
+    self.body.extend_from_slice(b"\r\n");
+    self
+  }
+
+  /// returns the content type header value and the encoded body
+  pub(crate) fn build(mut self) -> (String, Vec<u8>) {
+    self.body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+    (format!("multipart/form-data; boundary={}", self.boundary), self.body)
+  }
+}
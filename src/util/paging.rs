@@ -44,17 +44,38 @@
 //!     .page_size(PageSize::OneHundred)
 //!     .sort_order(SortOrder::Descending);
 //! ```
+//!
+//! Open Cloud endpoints page with tokens instead of cursors, so they return a
+//! [CloudPageIterator]. It is consumed the same way, and its page size can be
+//! changed with [CloudPageIterator::max_page_size]. A few older endpoints page
+//! with row indexes and return a [RowPageIterator] instead.
+
+use std::{future::poll_fn, pin::pin};
 
 use async_stream::stream;
 use async_trait::async_trait;
-use futures_core::stream::Stream;
+use futures_core::{future::BoxFuture, stream::Stream};
 
-use super::{api_helper, responses::PageResponse, RobloxResult};
+use reqwest::Url;
+use serde_json::{Map, Value};
+
+use super::{api_helper, responses::PageResponse, Credential, OxidError, RobloxResult};
 
 pub(crate) fn identity_mapper<T: Clone>(data: &T) -> T {
   data.clone()
 }
 
+// Used by helpers that need every item of a stream, such as bulk processing of join requests
+pub(crate) async fn collect_stream<T>(stream: impl Stream<Item = RobloxResult<T>>) -> RobloxResult<Vec<T>> {
+  let mut stream = pin!(stream);
+  let mut items = Vec::new();
+  while let Some(item) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+    items.push(item?);
+  }
+
+  Ok(items)
+}
+
 pub enum SortOrder {
   Ascending,
   Descending,
@@ -91,13 +112,13 @@ impl PageSize {
 
 // An iterator for all pages of a PageIterator
 struct PagesIterator<T> {
-  iterator: Box<dyn BasePageIterator<T>>,
+  iterator: Box<dyn BasePageIterator<T> + Send>,
   current_page_position: i32,
   current_page_data: Vec<T>,
 }
 
 impl<T: Clone> PagesIterator<T> {
-  fn new(iterator: Box<dyn BasePageIterator<T>>) -> Self {
+  fn new(iterator: Box<dyn BasePageIterator<T> + Send>) -> Self {
     Self {
       iterator,
       current_page_position: 0,
@@ -143,6 +164,8 @@ where
   U: Clone,
 {
   url: String,
+  // Finds the url when the first page is fetched, for endpoints that need another request to build it
+  url_resolver: Option<Box<dyn FnOnce() -> BoxFuture<'static, RobloxResult<String>> + Send>>,
   mapper: fn(&T) -> U,
   sort_order: SortOrder,
   page_size: PageSize,
@@ -159,6 +182,7 @@ where
   pub fn new(url: String, mapper: fn(&T) -> U, cookie: Option<String>) -> Self {
     Self {
       url,
+      url_resolver: None,
       mapper,
       sort_order: SortOrder::Ascending,
       page_size: PageSize::Ten,
@@ -168,6 +192,17 @@ where
     }
   }
 
+  pub(crate) fn with_url_resolver(
+    url_resolver: impl FnOnce() -> BoxFuture<'static, RobloxResult<String>> + Send + 'static,
+    mapper: fn(&T) -> U,
+    cookie: Option<String>,
+  ) -> Self {
+    Self {
+      url_resolver: Some(Box::new(url_resolver)),
+      ..Self::new(String::new(), mapper, cookie)
+    }
+  }
+
   pub fn sort_order(mut self, sort_order: SortOrder) -> Self {
     self.sort_order = sort_order;
     self
@@ -196,14 +231,20 @@ where
     }
     self.iteration_started = true;
 
+    if let Some(url_resolver) = self.url_resolver.take() {
+      self.url = url_resolver().await?;
+    }
+
     let page = api_helper::deserialize_body::<PageResponse<T>>(
       api_helper::get(
         format!(
-          "{}?sortOrder={}&limit={}&cursor={}",
+          "{}{}sortOrder={}&limit={}&cursor={}",
           self.url,
+          // Some urls already have a query, such as filters
+          if self.url.contains('?') { '&' } else { '?' },
           self.sort_order.serialize(),
           self.page_size.serialize(),
-          self.next_cursor.clone().unwrap_or(String::new())
+          self.next_cursor.clone().unwrap_or_default()
         ),
         self.cookie.clone(),
       )
@@ -216,3 +257,187 @@ where
     Ok(Some(page.data.iter().map(self.mapper).collect()))
   }
 }
+
+pub struct CloudPageIterator<T, U>
+where
+  T: serde::de::DeserializeOwned,
+  U: Clone,
+{
+  url: String,
+  // Open Cloud names the array of each page after the resource, e.g. "assetVersions"
+  items_key: &'static str,
+  mapper: fn(&T) -> U,
+  max_page_size: Option<u32>,
+  filter: Option<String>,
+  iteration_started: bool,
+  next_page_token: Option<String>,
+  credential: Option<Credential>,
+}
+
+impl<T, U> CloudPageIterator<T, U>
+where
+  T: serde::de::DeserializeOwned + Send + 'static,
+  U: Clone + Send + 'static,
+{
+  pub fn new(url: String, items_key: &'static str, mapper: fn(&T) -> U, credential: Option<Credential>) -> Self {
+    Self {
+      url,
+      items_key,
+      mapper,
+      max_page_size: None,
+      filter: None,
+      iteration_started: false,
+      next_page_token: None,
+      credential,
+    }
+  }
+
+  /// The maximum amount of items per page. Each endpoint has its own default and upper limit
+  pub fn max_page_size(mut self, max_page_size: u32) -> Self {
+    self.max_page_size = Some(max_page_size);
+    self
+  }
+
+  // Filters are built by the functions returning the iterator, as their syntax differs between endpoints
+  pub(crate) fn filter(mut self, filter: Option<String>) -> Self {
+    self.filter = filter;
+    self
+  }
+
+  pub fn into_stream(self) -> impl Stream<Item = RobloxResult<U>> {
+    PagesIterator::new(Box::new(self)).into_stream()
+  }
+
+  fn page_url(&self) -> RobloxResult<String> {
+    let mut url = Url::parse(&self.url).map_err(|e| OxidError::Validation(e.to_string()))?;
+
+    {
+      let mut query = url.query_pairs_mut();
+
+      if let Some(max_page_size) = self.max_page_size {
+        query.append_pair("maxPageSize", &max_page_size.to_string());
+      }
+      if let Some(filter) = &self.filter {
+        query.append_pair("filter", filter);
+      }
+      if let Some(page_token) = &self.next_page_token {
+        query.append_pair("pageToken", page_token);
+      }
+    }
+
+    Ok(url.to_string())
+  }
+}
+
+#[async_trait]
+impl<T, U> BasePageIterator<U> for CloudPageIterator<T, U>
+where
+  T: serde::de::DeserializeOwned + Send + 'static,
+  U: Clone + Send + 'static,
+{
+  async fn next_page(&mut self) -> RobloxResult<Option<Vec<U>>> {
+    if self.iteration_started && self.next_page_token.is_none() {
+      return Ok(None);
+    }
+    self.iteration_started = true;
+
+    let mut page =
+      api_helper::deserialize_body::<Map<String, Value>>(api_helper::cloud_get(self.page_url()?, self.credential.clone()).await?).await?;
+
+    // The last page has either no token or an empty one
+    self.next_page_token = match page.remove("nextPageToken") {
+      Some(Value::String(token)) if !token.is_empty() => Some(token),
+      _ => None,
+    };
+
+    // Empty pages omit the array entirely
+    let items = match page.remove(self.items_key) {
+      Some(items) => serde_json::from_value::<Vec<T>>(items).map_err(|e| OxidError::Validation(e.to_string()))?,
+      None => Vec::new(),
+    };
+
+    Ok(Some(items.iter().map(self.mapper).collect()))
+  }
+}
+
+/// Pages through older endpoints that use row indexes instead of cursors, such as group relationships
+pub struct RowPageIterator<T, U>
+where
+  T: serde::de::DeserializeOwned,
+  U: Clone,
+{
+  url: String,
+  items_key: &'static str,
+  mapper: fn(&T) -> U,
+  max_rows: u32,
+  next_row_index: Option<i64>,
+  cookie: Option<String>,
+}
+
+impl<T, U> RowPageIterator<T, U>
+where
+  T: serde::de::DeserializeOwned + Send + 'static,
+  U: Clone + Send + 'static,
+{
+  pub fn new(url: String, items_key: &'static str, mapper: fn(&T) -> U, cookie: Option<String>) -> Self {
+    Self {
+      url,
+      items_key,
+      mapper,
+      max_rows: 50,
+      next_row_index: Some(0),
+      cookie,
+    }
+  }
+
+  /// The maximum amount of items per page, 50 by default
+  pub fn max_rows(mut self, max_rows: u32) -> Self {
+    self.max_rows = max_rows;
+    self
+  }
+
+  pub fn into_stream(self) -> impl Stream<Item = RobloxResult<U>> {
+    PagesIterator::new(Box::new(self)).into_stream()
+  }
+}
+
+#[async_trait]
+impl<T, U> BasePageIterator<U> for RowPageIterator<T, U>
+where
+  T: serde::de::DeserializeOwned + Send + 'static,
+  U: Clone + Send + 'static,
+{
+  async fn next_page(&mut self) -> RobloxResult<Option<Vec<U>>> {
+    let Some(start_row_index) = self.next_row_index else {
+      return Ok(None);
+    };
+
+    let mut page = api_helper::deserialize_body::<Map<String, Value>>(
+      api_helper::get(
+        format!(
+          "{}{}StartRowIndex={}&MaxRows={}",
+          self.url,
+          if self.url.contains('?') { '&' } else { '?' },
+          start_row_index,
+          self.max_rows
+        ),
+        self.cookie.clone(),
+      )
+      .await?,
+    )
+    .await?;
+
+    let items = match page.remove(self.items_key) {
+      Some(items) => serde_json::from_value::<Vec<T>>(items).map_err(|e| OxidError::Validation(e.to_string()))?,
+      None => Vec::new(),
+    };
+
+    // A page that isn't full is the last one
+    self.next_row_index = match page.remove("nextRowIndex").and_then(|index| index.as_i64()) {
+      Some(index) if items.len() as u32 >= self.max_rows => Some(index),
+      _ => None,
+    };
+
+    Ok(Some(items.iter().map(self.mapper).collect()))
+  }
+}
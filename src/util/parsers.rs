@@ -1,21 +1,54 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use chrono::{DateTime, Utc};
 use lazy_static::lazy_static;
 use serde::{de::Error, Deserialize, Deserializer};
 
 use crate::{
-  bases::{BaseAsset, BaseUniverse, BaseUser},
-  models::{AssetType, CreatorType, SkinnyGroup, SkinnyUser},
+  bases::{BaseAsset, BaseGroup, BaseUniverse, BaseUser},
+  models::{AssetType, CloudCreator, CreatorType, RestrictionModerator, SkinnyGroup, SkinnyUser},
 };
 
-use super::responses::{AssetCreatorResponse, UniverseCreatorResponse};
+use super::responses::{AssetCreatorResponse, CloudCreatorResponse, RestrictionModeratorResponse, StringOrInt, UniverseCreatorResponse};
+
+// Open Cloud v2 references other resources by their path, such as "users/123"
+fn id_from_resource_path(path: &str) -> Result<i64, String> {
+  path
+    .rsplit('/')
+    .next()
+    .and_then(|id| id.parse().ok())
+    .ok_or(format!("{} is not a resource path", path))
+}
 
 pub fn parse_iso8601_date<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
 where
   D: Deserializer<'de>,
 {
-  Deserialize::deserialize(deserializer).and_then(|date_string: &str| date_string.parse::<DateTime<Utc>>().map_err(Error::custom))
+  Deserialize::deserialize(deserializer).and_then(|date_string: String| date_string.parse::<DateTime<Utc>>().map_err(Error::custom))
+}
+
+pub fn parse_optional_iso8601_date<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|date_string: Option<String>| {
+    date_string
+      .map(|date_string| date_string.parse::<DateTime<Utc>>())
+      .transpose()
+      .map_err(Error::custom)
+  })
+}
+
+pub fn parse_optional_unix_timestamp<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|timestamp: Option<i64>| {
+    timestamp
+      .map(|timestamp| DateTime::from_timestamp(timestamp, 0).ok_or(format!("{} is not a valid timestamp", timestamp)))
+      .transpose()
+      .map_err(Error::custom)
+  })
 }
 
 pub fn parse_base_asset<'de, D>(deserializer: D) -> Result<BaseAsset, D::Error>
@@ -46,6 +79,92 @@ where
   Deserialize::deserialize(deserializer).map(|id: Option<i64>| id.map(|id| BaseUniverse { id }))
 }
 
+/// Open Cloud serializes int64 fields as strings
+pub fn parse_string_i64<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|id: StringOrInt| id.into_i64().map_err(Error::custom))
+}
+
+pub fn parse_optional_string_i64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|id: Option<StringOrInt>| id.map(|id| id.into_i64()).transpose().map_err(Error::custom))
+}
+
+pub fn parse_resource_base_user<'de, D>(deserializer: D) -> Result<BaseUser, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|path: String| id_from_resource_path(&path).map(|id| BaseUser { id }).map_err(Error::custom))
+}
+
+pub fn parse_optional_resource_base_user<'de, D>(deserializer: D) -> Result<Option<BaseUser>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  parse_optional_resource_id(deserializer).map(|id| id.map(|id| BaseUser { id }))
+}
+
+pub fn parse_resource_id<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|path: String| id_from_resource_path(&path).map_err(Error::custom))
+}
+
+pub fn parse_optional_resource_id<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer)
+    .and_then(|path: Option<String>| path.map(|path| id_from_resource_path(&path)).transpose().map_err(Error::custom))
+}
+
+/// Open Cloud serializes durations as seconds with an "s" suffix, e.g. "3600s" or "0.5s"
+pub fn parse_optional_cloud_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|duration: Option<String>| {
+    duration
+      .map(|duration| {
+        duration
+          .trim_end_matches('s')
+          .parse::<f64>()
+          .map(Duration::from_secs_f64)
+          .map_err(|_| format!("{} is not a duration", duration))
+      })
+      .transpose()
+      .map_err(Error::custom)
+  })
+}
+
+pub fn parse_restriction_moderator<'de, D>(deserializer: D) -> Result<RestrictionModerator, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|moderator: RestrictionModeratorResponse| match moderator.roblox_user {
+    Some(path) => id_from_resource_path(&path)
+      .map(|id| RestrictionModerator::User(BaseUser { id }))
+      .map_err(Error::custom),
+    None => Ok(RestrictionModerator::GameServerScript),
+  })
+}
+
+pub fn parse_cloud_creator<'de, D>(deserializer: D) -> Result<CloudCreator, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Deserialize::deserialize(deserializer).and_then(|creator: CloudCreatorResponse| match (creator.user_id, creator.group_id) {
+    (Some(id), _) => Ok(CloudCreator::User(BaseUser { id })),
+    (_, Some(id)) => Ok(CloudCreator::Group(BaseGroup { id })),
+    _ => Err(Error::custom("creator has neither a userId nor a groupId")),
+  })
+}
+
 pub fn parse_asset_creator<'de, D>(deserializer: D) -> Result<CreatorType, D::Error>
 where
   D: Deserializer<'de>,
@@ -0,0 +1,97 @@
+//! Polling functionality for long-running Open Cloud requests
+//!
+//! Some Open Cloud requests, such as uploading an asset, don't finish before
+//! the response is sent. They instead return a resource that has to be
+//! fetched again until it is done, like an [Operation](crate::models::Operation).
+//! Waiting for these polls the resource with an exponential [Backoff]:
+//!
+//! ```rust
+//! let operation = oxid_roblox::open_cloud::create_asset(/* ... */).await.unwrap();
+//!
+//! let asset = operation
+//!     .wait_with(Backoff::default().max_attempts(50))
+//!     .await
+//!     .unwrap();
+//! ```
+
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+
+use super::{api_helper, Credential, OxidError, ResultExtensions, RobloxResult};
+
+/// How often a long-running resource is polled until it is done
+#[derive(Debug, Clone)]
+pub struct Backoff {
+  initial_delay: Duration,
+  max_delay: Duration,
+  multiplier: u32,
+  max_attempts: u32,
+}
+
+impl Default for Backoff {
+  fn default() -> Self {
+    Self {
+      initial_delay: Duration::from_secs(1),
+      max_delay: Duration::from_secs(30),
+      multiplier: 2,
+      max_attempts: 20,
+    }
+  }
+}
+
+impl Backoff {
+  /// The delay before the first poll
+  pub fn initial_delay(mut self, initial_delay: Duration) -> Self {
+    self.initial_delay = initial_delay;
+    self
+  }
+
+  /// The upper bound of the delay between polls
+  pub fn max_delay(mut self, max_delay: Duration) -> Self {
+    self.max_delay = max_delay;
+    self
+  }
+
+  /// How much the delay grows after each poll
+  pub fn multiplier(mut self, multiplier: u32) -> Self {
+    self.multiplier = multiplier.max(1);
+    self
+  }
+
+  /// How many polls are sent before giving up with [OxidError::Validation]
+  pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+    self.max_attempts = max_attempts;
+    self
+  }
+}
+
+/// A resource returned by a long-running request
+pub(crate) trait Pollable: DeserializeOwned {
+  fn is_done(&self) -> bool;
+}
+
+/// fetches the resource at `url` until it is done, sleeping between each poll according to the backoff
+pub(crate) async fn poll_until_done<R: Pollable>(url: String, credential: Option<Credential>, backoff: &Backoff) -> RobloxResult<R> {
+  let mut delay = backoff.initial_delay;
+
+  for _ in 0..backoff.max_attempts {
+    tokio::time::sleep(delay).await;
+
+    let resource = api_helper::cloud_get(url.clone(), credential.clone())
+      .await
+      .map_async(api_helper::deserialize_body::<R>)
+      .await??;
+
+    if resource.is_done() {
+      return Ok(resource);
+    }
+
+    delay = (delay * backoff.multiplier).min(backoff.max_delay);
+  }
+
+  Err(OxidError::Validation(format!(
+    "{} was not done after {} attempts",
+    url, backoff.max_attempts
+  )))
+}
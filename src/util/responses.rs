@@ -1,9 +1,19 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
 
-use crate::models::{GroupRole, Presence, SkinnyGroupWithMemberCount, SkinnyRole};
+use crate::{
+  bases::{BaseAsset, BaseGroup, BaseUniverse, BaseUser},
+  models::{
+    AuditLogAction, AuditLogEntry, AuthorizedResources, CloudCreator, GroupRole, InventoryItem, InventoryItemKind, Member, OAuthTokens, Presence,
+    SkinnyGroupWithMemberCount, SkinnyRole,
+  },
+};
 
-use super::{parsers::parse_iso8601_date, ApiError};
+use super::{
+  parsers::{parse_iso8601_date, parse_optional_iso8601_date, parse_optional_string_i64, parse_string_i64},
+  ApiError,
+};
 
 #[derive(Deserialize)]
 pub struct ErrorResponse {
@@ -47,6 +57,42 @@ pub struct UsernameHistoryResponse {
   pub name: String,
 }
 
+#[derive(Deserialize)]
+pub struct GroupNameHistoryResponse {
+  pub name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupDescriptionResponse {
+  pub new_description: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupNameResponse {
+  pub new_name: String,
+}
+
+#[derive(Deserialize)]
+pub struct RootPlaceResponse {
+  pub id: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatorGameResponse {
+  pub id: i64,
+  pub name: String,
+  pub root_place: RootPlaceResponse,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniverseFavoriteResponse {
+  pub is_favorited: bool,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UniverseFavoriteCountResponse {
@@ -56,7 +102,6 @@ pub struct UniverseFavoriteCountResponse {
 #[derive(Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AssetCreatorResponse {
-  pub id: i64,
   pub name: String,
   pub creator_type: String,
   pub creator_target_id: i64,
@@ -76,7 +121,6 @@ pub struct UniverseCreatorResponse {
 #[derive(Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PresenceLastOnline {
-  pub user_id: i64,
   #[serde(deserialize_with = "parse_iso8601_date")]
   pub last_online: DateTime<Utc>,
 }
@@ -90,7 +134,290 @@ pub struct PresenceLastOnlineResponse {
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PageResponse<T> {
-  pub previous_page_cursor: Option<String>,
   pub next_page_cursor: Option<String>,
   pub data: Vec<T>,
 }
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum StringOrInt {
+  String(String),
+  Int(i64),
+}
+
+impl StringOrInt {
+  pub fn into_i64(self) -> Result<i64, std::num::ParseIntError> {
+    match self {
+      StringOrInt::String(s) => s.parse(),
+      StringOrInt::Int(i) => Ok(i),
+    }
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudCreatorResponse {
+  #[serde(default, deserialize_with = "parse_optional_string_i64")]
+  pub user_id: Option<i64>,
+  #[serde(default, deserialize_with = "parse_optional_string_i64")]
+  pub group_id: Option<i64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestrictionModeratorResponse {
+  pub roblox_user: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudGroupRoleResponse {
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub id: i64,
+  pub display_name: String,
+  pub description: Option<String>,
+  pub rank: u8,
+  pub member_count: Option<i32>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryAssetDetailsResponse {
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub asset_id: i64,
+  #[serde(default)]
+  pub inventory_item_asset_type: String,
+  #[serde(default, deserialize_with = "parse_optional_string_i64")]
+  pub instance_id: Option<i64>,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryBadgeDetailsResponse {
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub badge_id: i64,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryGamePassDetailsResponse {
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub game_pass_id: i64,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryPrivateServerDetailsResponse {
+  #[serde(deserialize_with = "parse_string_i64")]
+  pub private_server_id: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryItemResponse {
+  pub path: String,
+  pub asset_details: Option<InventoryAssetDetailsResponse>,
+  pub badge_details: Option<InventoryBadgeDetailsResponse>,
+  pub game_pass_details: Option<InventoryGamePassDetailsResponse>,
+  pub private_server_details: Option<InventoryPrivateServerDetailsResponse>,
+  #[serde(default, deserialize_with = "parse_optional_iso8601_date")]
+  pub add_time: Option<DateTime<Utc>>,
+}
+
+impl InventoryItemResponse {
+  // At most one of the details is set, depending on the kind of item
+  pub fn to_item(&self) -> InventoryItem {
+    let kind = if let Some(details) = &self.asset_details {
+      InventoryItemKind::Asset {
+        asset: BaseAsset { id: details.asset_id },
+        asset_type: details.inventory_item_asset_type.clone(),
+        instance_id: details.instance_id,
+      }
+    } else if let Some(details) = &self.badge_details {
+      InventoryItemKind::Badge(details.badge_id)
+    } else if let Some(details) = &self.game_pass_details {
+      InventoryItemKind::GamePass(details.game_pass_id)
+    } else if let Some(details) = &self.private_server_details {
+      InventoryItemKind::PrivateServer(details.private_server_id)
+    } else {
+      InventoryItemKind::Unknown
+    };
+
+    InventoryItem {
+      path: self.path.clone(),
+      kind,
+      add_time: self.add_time,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+pub struct OAuthTokenResponse {
+  pub access_token: String,
+  pub refresh_token: Option<String>,
+  pub token_type: String,
+  pub expires_in: i64,
+  #[serde(default)]
+  pub scope: String,
+  pub id_token: Option<String>,
+}
+
+impl OAuthTokenResponse {
+  // expires_in is relative to when the token was issued, which is about when the response was received
+  pub fn into_tokens(self) -> OAuthTokens {
+    OAuthTokens {
+      access_token: self.access_token,
+      refresh_token: self.refresh_token,
+      token_type: self.token_type,
+      scope: self.scope,
+      id_token: self.id_token,
+      expires_at: Utc::now() + chrono::Duration::seconds(self.expires_in),
+    }
+  }
+}
+
+#[derive(Deserialize, Default)]
+pub struct ResourceIdsResponse {
+  #[serde(default)]
+  pub ids: Vec<StringOrInt>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct CreatorIdsResponse {
+  #[serde(alias = "U", default)]
+  #[serde(rename = "User")]
+  pub users: Vec<StringOrInt>,
+  #[serde(alias = "G", default)]
+  #[serde(rename = "Group")]
+  pub groups: Vec<StringOrInt>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct CreatorResourceResponse {
+  #[serde(default)]
+  pub ids: CreatorIdsResponse,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ResourcesResponse {
+  #[serde(default)]
+  pub universe: ResourceIdsResponse,
+  #[serde(default)]
+  pub creator: CreatorResourceResponse,
+}
+
+#[derive(Deserialize)]
+pub struct ResourceOwnerResponse {
+  pub id: StringOrInt,
+  #[serde(rename = "type")]
+  pub owner_type: String,
+}
+
+#[derive(Deserialize)]
+pub struct ResourceInfoResponse {
+  pub owner: ResourceOwnerResponse,
+  #[serde(default)]
+  pub resources: ResourcesResponse,
+}
+
+#[derive(Deserialize)]
+pub struct TokenResourcesResponse {
+  pub resource_infos: Vec<ResourceInfoResponse>,
+}
+
+impl ResourceInfoResponse {
+  pub fn into_resources(self) -> Result<AuthorizedResources, std::num::ParseIntError> {
+    let owner_id = self.owner.id.into_i64()?;
+    let owner = if self.owner.owner_type == "Group" {
+      CloudCreator::Group(BaseGroup { id: owner_id })
+    } else {
+      CloudCreator::User(BaseUser { id: owner_id })
+    };
+
+    Ok(AuthorizedResources {
+      owner,
+      universes: self
+        .resources
+        .universe
+        .ids
+        .into_iter()
+        .map(|id| id.into_i64().map(|id| BaseUniverse { id }))
+        .collect::<Result<_, _>>()?,
+      users: self
+        .resources
+        .creator
+        .ids
+        .users
+        .into_iter()
+        .map(|id| id.into_i64().map(|id| BaseUser { id }))
+        .collect::<Result<_, _>>()?,
+      groups: self
+        .resources
+        .creator
+        .ids
+        .groups
+        .into_iter()
+        .map(|id| id.into_i64().map(|id| BaseGroup { id }))
+        .collect::<Result<_, _>>()?,
+    })
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntryResponse {
+  pub actor: Member,
+  pub action_type: String,
+  #[serde(default)]
+  pub description: Value,
+  #[serde(deserialize_with = "parse_iso8601_date")]
+  pub created: DateTime<Utc>,
+}
+
+impl AuditLogEntryResponse {
+  pub fn to_entry(&self) -> AuditLogEntry {
+    AuditLogEntry {
+      actor: self.actor.clone(),
+      action: self.to_action().unwrap_or_else(|| AuditLogAction::Other {
+        action_type: self.action_type.clone(),
+        description: self.description.clone(),
+      }),
+      created: self.created,
+    }
+  }
+
+  fn payload<T: DeserializeOwned>(&self) -> Option<T> {
+    serde_json::from_value(self.description.clone()).ok()
+  }
+
+  // The action type is returned with spaces ("Change Rank") while the actionType filter doesn't have any ("ChangeRank")
+  fn to_action(&self) -> Option<AuditLogAction> {
+    Some(match self.action_type.replace(' ', "").as_str() {
+      "ChangeRank" => AuditLogAction::ChangeRank(self.payload()?),
+      "RemoveMember" => AuditLogAction::RemoveMember(self.payload()?),
+      "AcceptJoinRequest" => AuditLogAction::AcceptJoinRequest(self.payload()?),
+      "DeclineJoinRequest" => AuditLogAction::DeclineJoinRequest(self.payload()?),
+      "BanMember" => AuditLogAction::BanMember(self.payload()?),
+      "UnbanMember" => AuditLogAction::UnbanMember(self.payload()?),
+      "DeletePost" => AuditLogAction::DeletePost(self.payload()?),
+      "PostStatus" | "PostShout" => AuditLogAction::PostStatus(self.payload()?),
+      "SpendGroupFunds" => AuditLogAction::SpendGroupFunds(self.payload()?),
+      "ChangeDescription" => AuditLogAction::ChangeDescription(self.payload()?),
+      "SendAllyRequest" => AuditLogAction::SendAllyRequest(self.payload()?),
+      "AcceptAllyRequest" => AuditLogAction::AcceptAllyRequest(self.payload()?),
+      "DeclineAllyRequest" => AuditLogAction::DeclineAllyRequest(self.payload()?),
+      "DeleteAlly" => AuditLogAction::DeleteAlly(self.payload()?),
+      "CreateEnemy" => AuditLogAction::CreateEnemy(self.payload()?),
+      "DeleteEnemy" => AuditLogAction::DeleteEnemy(self.payload()?),
+      "UpdateRolesetRank" => AuditLogAction::UpdateRolesetRank(self.payload()?),
+      "UpdateRolesetData" => AuditLogAction::UpdateRolesetData(self.payload()?),
+      _ => return None,
+    })
+  }
+}
+
+impl From<AuditLogEntryResponse> for AuditLogEntry {
+  fn from(response: AuditLogEntryResponse) -> Self {
+    response.to_entry()
+  }
+}
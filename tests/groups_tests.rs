@@ -1,7 +1,12 @@
-//!
-
+use chrono::{DateTime, Duration, Utc};
 use dotenv::dotenv;
-use oxid_roblox::derives::{GroupDerive};
+use oxid_roblox::{
+  derives::GroupDerive,
+  models::{AuditLogAction, AuditLogEntry, JoinRequestRejection, JoinRequestRules, User, WallPost, WallPostFilter},
+  util::OxidError,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
 use std::env;
 
 #[tokio::test]
@@ -23,3 +28,204 @@ async fn roles() {
   assert!(roles.is_ok());
 }
 
+#[tokio::test]
+async fn payout_rejected_before_request() {
+  let group = oxid_roblox::base_group(1);
+
+  let result = group.payout(&[(1, 0)], None).await;
+  assert!(matches!(result, Err(OxidError::Validation(_))));
+
+  let result = group.set_recurring_payouts(&[(1, 101)], None).await;
+  assert!(matches!(result, Err(OxidError::Validation(_))));
+
+  let result = group.set_recurring_payouts(&[(1, i32::MAX), (2, 1)], None).await;
+  assert!(matches!(result, Err(OxidError::Validation(_))));
+}
+
+// Fixtures are built as json and deserialized, the same way the API responses are
+fn fixture<T: DeserializeOwned>(value: Value) -> T {
+  serde_json::from_value(value).expect("Failed to deserialize fixture")
+}
+
+fn member(user_id: i64, role_name: &str, rank: u8) -> Value {
+  json!({
+    "user": { "hasVerifiedBadge": false, "userId": user_id, "username": "Roblox", "displayName": "Roblox" },
+    "role": { "id": 12, "name": role_name, "rank": rank }
+  })
+}
+
+fn audit_log_entry(action_type: &str, description: Value) -> AuditLogEntry {
+  fixture(json!({
+    "actor": member(1, "Owner", 255),
+    "actionType": action_type,
+    "description": description,
+    "created": "2024-03-02T10:15:00.123Z"
+  }))
+}
+
+#[test]
+fn audit_log_change_rank() {
+  let entry = audit_log_entry(
+    "Change Rank",
+    json!({
+      "TargetId": 2,
+      "TargetName": "John",
+      "OldRoleSetId": 10,
+      "OldRoleSetName": "Member",
+      "NewRoleSetId": 11,
+      "NewRoleSetName": "Admin"
+    }),
+  );
+
+  assert_eq!(entry.actor.user.id, 1);
+  assert_eq!(entry.actor.role.rank, 255);
+  match entry.action {
+    AuditLogAction::ChangeRank(change) => {
+      assert_eq!(change.target_id, 2);
+      assert_eq!(change.old_role_set_id, 10);
+      assert_eq!(change.new_role_set_id, 11);
+      assert_eq!(change.new_role_set_name, "Admin");
+    }
+    action => panic!("Expected a rank change, got {:?}", action),
+  }
+}
+
+#[test]
+fn audit_log_remove_member() {
+  let entry = audit_log_entry("Remove Member", json!({ "TargetId": 3, "TargetName": "Jane" }));
+
+  match entry.action {
+    AuditLogAction::RemoveMember(target) => {
+      assert_eq!(target.target_id, 3);
+      assert_eq!(target.target_name, "Jane");
+    }
+    action => panic!("Expected a member removal, got {:?}", action),
+  }
+}
+
+#[test]
+fn audit_log_delete_post() {
+  let entry = audit_log_entry("Delete Post", json!({ "PostDesc": "Free robux", "TargetId": 4, "TargetName": "Spammer" }));
+
+  match entry.action {
+    AuditLogAction::DeletePost(deletion) => {
+      assert_eq!(deletion.post_body, "Free robux");
+      assert_eq!(deletion.target_id, 4);
+    }
+    action => panic!("Expected a post deletion, got {:?}", action),
+  }
+}
+
+#[test]
+fn audit_log_unknown_payload() {
+  let entry = audit_log_entry("Change Rank", json!({ "TargetId": 2 }));
+
+  assert!(matches!(entry.action, AuditLogAction::Other { ref action_type, .. } if action_type == "Change Rank"));
+}
+
+fn wall_post(body: &str, poster_id: Option<i64>, created: &str) -> WallPost {
+  fixture(json!({
+    "id": 1,
+    "poster": poster_id.map(|id| member(id, "Member", 1)),
+    "body": body,
+    "created": created,
+    "updated": created
+  }))
+}
+
+#[test]
+fn wall_post_filter() {
+  let post = wall_post("Join my FREE robux group", Some(5), "2024-03-02T10:15:00Z");
+  let deleted_user_post = wall_post("free robux", None, "2024-03-02T10:15:00Z");
+
+  assert!(WallPostFilter::default().matches(&post));
+
+  let keyword = WallPostFilter {
+    keyword: Some("free Robux".to_owned()),
+    ..Default::default()
+  };
+  assert!(keyword.matches(&post));
+  assert!(keyword.matches(&deleted_user_post));
+  assert!(!keyword.matches(&wall_post("Hello", Some(5), "2024-03-02T10:15:00Z")));
+  assert!(keyword.matches(&wall_post(r#"He said "free robux" again"#, Some(5), "2024-03-02T10:15:00Z")));
+
+  let user = WallPostFilter {
+    user_id: Some(5),
+    ..Default::default()
+  };
+  assert!(user.matches(&post));
+  assert!(!user.matches(&deleted_user_post));
+  assert!(!user.matches(&wall_post("Join my FREE robux group", Some(6), "2024-03-02T10:15:00Z")));
+
+  let date: DateTime<Utc> = "2024-03-01T00:00:00Z".parse().unwrap();
+  let before = WallPostFilter {
+    created_before: Some(date),
+    ..Default::default()
+  };
+  let after = WallPostFilter {
+    created_after: Some(date),
+    ..Default::default()
+  };
+  assert!(!before.matches(&post));
+  assert!(after.matches(&post));
+
+  // Every filter that is set has to match
+  let combined = WallPostFilter {
+    keyword: Some("robux".to_owned()),
+    user_id: Some(6),
+    created_after: Some(date),
+    ..Default::default()
+  };
+  assert!(!combined.matches(&post));
+}
+
+fn requester(created: DateTime<Utc>, is_banned: bool, has_verified_badge: bool) -> User {
+  fixture(json!({
+    "description": "",
+    "created": created.to_rfc3339(),
+    "isBanned": is_banned,
+    "externalAppDisplayName": null,
+    "hasVerifiedBadge": has_verified_badge,
+    "id": 1,
+    "name": "Requester",
+    "displayName": "Requester"
+  }))
+}
+
+#[test]
+fn join_request_rules() {
+  let old = Utc::now() - Duration::days(365);
+  let new = Utc::now() - Duration::days(2);
+
+  assert_eq!(JoinRequestRules::default().evaluate(&requester(new, true, false), false), None);
+
+  let rules = JoinRequestRules {
+    min_account_age: Some(Duration::days(30)),
+    require_verified_badge: true,
+    require_allied_group: true,
+    reject_banned: true,
+    decline_failing: false,
+  };
+  assert_eq!(rules.evaluate(&requester(old, false, true), true), None);
+  assert_eq!(
+    rules.evaluate(&requester(old, false, true), false),
+    Some(JoinRequestRejection::NotInAlliedGroup)
+  );
+  assert_eq!(
+    rules.evaluate(&requester(old, false, false), true),
+    Some(JoinRequestRejection::NotVerified)
+  );
+  assert_eq!(
+    rules.evaluate(&requester(new, false, true), true),
+    Some(JoinRequestRejection::AccountTooNew)
+  );
+
+  // The first failing rule is reported
+  assert_eq!(rules.evaluate(&requester(new, true, false), false), Some(JoinRequestRejection::Banned));
+
+  let rules = JoinRequestRules {
+    min_account_age: Some(Duration::days(30)),
+    ..Default::default()
+  };
+  assert_eq!(rules.evaluate(&requester(old, true, false), false), None);
+}
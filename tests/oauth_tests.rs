@@ -0,0 +1,60 @@
+use chrono::Utc;
+use oxid_roblox::{
+  models::OAuthTokens,
+  oauth::{FileTokenStore, OAuthClient, Pkce, TokenStore},
+};
+
+#[test]
+fn pkce() {
+  // Test vector from RFC 7636 appendix B
+  let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+  assert_eq!(pkce.challenge, "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+
+  let random = Pkce::new();
+  assert!((43..=128).contains(&random.verifier.len()));
+  assert_ne!(random.verifier, Pkce::new().verifier);
+}
+
+#[test]
+fn authorization_url() {
+  let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+  let url = OAuthClient::new("1234", None, "https://example.com/callback")
+    .authorization_url()
+    .scopes(&["openid", "profile"])
+    .state("abc")
+    .pkce(&pkce)
+    .build();
+
+  assert!(url.starts_with("https://apis.roblox.com/oauth/v1/authorize?client_id=1234"));
+  assert!(url.contains("redirect_uri=https%3A%2F%2Fexample.com%2Fcallback"));
+  assert!(url.contains("scope=openid+profile"));
+  assert!(url.contains("state=abc"));
+  assert!(url.contains("code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&code_challenge_method=S256"));
+}
+
+#[tokio::test]
+async fn file_token_store() {
+  let path = std::env::temp_dir().join("oxid_roblox_file_token_store.json");
+  let _ = std::fs::remove_file(&path);
+
+  let store = FileTokenStore::new(&path);
+  assert!(store.load().await.unwrap().is_none());
+
+  store
+    .save(&OAuthTokens {
+      access_token: "access".to_owned(),
+      refresh_token: Some("refresh".to_owned()),
+      token_type: "Bearer".to_owned(),
+      scope: "openid profile".to_owned(),
+      id_token: None,
+      expires_at: Utc::now(),
+    })
+    .await
+    .unwrap();
+
+  let tokens = store.load().await.unwrap().unwrap();
+  assert_eq!(tokens.refresh_token.as_deref(), Some("refresh"));
+  assert!(tokens.is_expired());
+
+  std::fs::remove_file(&path).unwrap();
+}
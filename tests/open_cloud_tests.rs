@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use oxid_roblox::{
+  models::{CloudAsset, CloudAssetType, CloudCreator, Operation, RestrictionModerator, UserRestrictionLog},
+  open_cloud::{publish_place, AssetFile, PlaceVersionType, PLACE_FILE_SIZE_LIMIT},
+  util::OxidError,
+};
+
+#[test]
+fn asset_file_content_type() {
+  assert_eq!(AssetFile::new("decal.PNG", vec![]).content_type, "image/png");
+  assert_eq!(AssetFile::new("sword.fbx", vec![]).content_type, "model/fbx");
+  assert_eq!(AssetFile::new("theme.ogg", vec![]).content_type, "audio/ogg");
+  assert_eq!(AssetFile::new("unknown", vec![]).content_type, "application/octet-stream");
+}
+
+#[test]
+fn operation() {
+  let operation: Operation<CloudAsset> = serde_json::from_str(
+    r#"{
+      "path": "operations/6b6f1a1e",
+      "done": true,
+      "response": {
+        "@type": "type.googleapis.com/roblox.open_cloud.assets.v1.Asset",
+        "path": "assets/1234",
+        "assetId": "1234",
+        "assetType": "Decal",
+        "displayName": "Logo",
+        "description": "",
+        "creationContext": { "creator": { "groupId": "5678" } },
+        "moderationResult": { "moderationState": "Approved" }
+      }
+    }"#,
+  )
+  .unwrap();
+
+  assert_eq!(operation.id(), "6b6f1a1e");
+
+  let asset = operation.response.unwrap();
+  assert_eq!(asset.id, 1234);
+  assert_eq!(asset.asset_type, CloudAssetType::Decal);
+  assert!(matches!(asset.creation_context.creator, CloudCreator::Group(group) if group.id == 5678));
+}
+
+#[test]
+fn user_restriction_log() {
+  let log: UserRestrictionLog = serde_json::from_str(
+    r#"{
+      "user": "users/156",
+      "place": "places/1818",
+      "moderator": { "robloxUser": "users/261" },
+      "createTime": "2024-05-23T19:12:34.104Z",
+      "active": true,
+      "startTime": "2024-05-23T19:12:34.104Z",
+      "duration": "86400s",
+      "privateReason": "exploiting",
+      "displayReason": "Banned for exploiting",
+      "excludeAltAccounts": false
+    }"#,
+  )
+  .unwrap();
+
+  assert_eq!(log.user.id, 156);
+  assert_eq!(log.place_id, Some(1818));
+  assert_eq!(log.duration, Some(Duration::from_secs(86400)));
+  assert!(matches!(log.moderator, RestrictionModerator::User(user) if user.id == 261));
+}
+
+#[tokio::test]
+async fn publish_place_too_large() {
+  let place_file = vec![0; PLACE_FILE_SIZE_LIMIT + 1];
+  let result = publish_place(1, 1, place_file, PlaceVersionType::Saved, None).await;
+
+  assert!(matches!(result, Err(OxidError::PayloadTooLarge)));
+}
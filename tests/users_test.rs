@@ -1,5 +1,3 @@
-//!
-
 use dotenv::dotenv;
 use oxid_roblox::derives::UserDerive;
 use std::env;